@@ -1,7 +1,9 @@
-use bjc::game::PreBlackJackGame;
-use bjc::models::{Deck, Rule};
-use criterion::{criterion_group, criterion_main, Criterion};
+use bjc::game::{EvControl, PreBlackJackGame, Solver};
+use bjc::models::{DealerProbEngine, Deck, Rule};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use num::rational::Ratio;
+use num::BigInt;
+use std::sync::Arc;
 
 fn bench() {
     let deck = Deck::new(8);
@@ -21,8 +23,64 @@ fn bench() {
 }
 
 fn criterion_bench(c: &mut Criterion) {
-    c.bench_function("evaluate_hand (133,784,560 hands)", |b| b.iter(|| bench()));
+    c.bench_function("evaluate_hand (133,784,560 hands)", |b| b.iter(bench));
 }
 
-criterion_group!(benches, criterion_bench);
+fn dealer_engine_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dealer_hand_prob");
+    for (name, deck) in [
+        ("8 decks", Deck::new(8)),
+        (
+            "1 deck, 5 cards dealt",
+            Deck::new(1).remove_deck(&Deck::new_from_strs(&vec!["T", "T", "5", "6", "A"])),
+        ),
+    ] {
+        for engine in [DealerProbEngine::Pattern, DealerProbEngine::Recursive] {
+            group.bench_function(format!("{:?} ({})", engine, name), |b| {
+                b.iter(|| black_box(&deck).calc_dealer_hand_prob_with(engine))
+            });
+        }
+    }
+    group.finish();
+}
+
+// 8デッキの pre_round をスレッド数を変えて計算する. 毎回新しい Solver で、キャッシュなしから
+fn scaling_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pre_round_scaling");
+    group.sample_size(10);
+
+    let max_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let mut threads = vec![1];
+    while threads.last().unwrap() * 2 <= max_threads {
+        threads.push(threads.last().unwrap() * 2);
+    }
+    if *threads.last().unwrap() != max_threads {
+        threads.push(max_threads);
+    }
+
+    for num_threads in threads {
+        let pool = Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap(),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("8 decks", num_threads),
+            &num_threads,
+            |b, _| {
+                b.iter(|| {
+                    let solver = Solver::new().with_thread_pool(Arc::clone(&pool));
+                    let pre_round = PreBlackJackGame::new(Rule::evolution_classic(), Deck::new(8));
+                    solver
+                        .pre_round_ev::<Ratio<BigInt>>(&pre_round, &EvControl::new())
+                        .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_bench, dealer_engine_bench, scaling_bench);
 criterion_main!(benches);
@@ -0,0 +1,443 @@
+use crate::models::{DealerHandProb, Deck};
+
+use num::bigint::BigInt;
+use num::rational::Ratio;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::mem::size_of;
+use std::ops::Add;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+// キャッシュの1エントリが使うメモリのおおよそのバイト数
+pub trait CacheWeight {
+    fn cache_weight(&self) -> usize;
+}
+
+impl CacheWeight for Ratio<BigInt> {
+    fn cache_weight(&self) -> usize {
+        size_of::<Self>() + (self.numer().bits() + self.denom().bits()).div_ceil(8) as usize
+    }
+}
+
+impl CacheWeight for f64 {
+    fn cache_weight(&self) -> usize {
+        size_of::<Self>()
+    }
+}
+
+impl CacheWeight for Deck {
+    fn cache_weight(&self) -> usize {
+        size_of::<Self>()
+    }
+}
+
+impl CacheWeight for Arc<DealerHandProb> {
+    fn cache_weight(&self) -> usize {
+        size_of::<Self>() + size_of::<DealerHandProb>()
+    }
+}
+
+// 予算を超えたら、この割合まで古いものから捨てる. 毎回1件ずつ捨てるとソートが重いため
+const EVICT_TO_NUMER: usize = 3;
+const EVICT_TO_DENOM: usize = 4;
+
+// HashMap のエントリごとのオーバーヘッドの目安
+const ENTRY_OVERHEAD: usize = 2 * size_of::<usize>();
+
+struct Entry<V> {
+    value: V,
+    weight: usize,
+    last_used: AtomicU64,
+}
+
+// シャードの数. rayon で並列にたどるとき、同じロックを取り合わないように分ける
+const SHARDS: usize = 32;
+
+// 隣のシャードのカウンターと同じキャッシュラインに乗らないようにする
+#[repr(align(128))]
+struct Shard<K, V> {
+    map: RwLock<HashMap<K, Entry<V>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<K, V> Shard<K, V> {
+    fn new() -> Self {
+        Shard {
+            map: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+}
+
+// メモリ予算つきのキャッシュ. 予算を超えたら最近使われていないものから捨てる
+// キーのハッシュでシャードに分け、読み込みはそのシャードの read lock だけで済ませる
+// 最終使用時刻は挿入ごとに進む時計で、読み込みでは時計を進めない (おおよその LRU)
+pub struct BoundedCache<K, V> {
+    shards: Box<[Shard<K, V>]>,
+    hasher: RandomState,
+    clock: AtomicU64,
+    bytes: AtomicUsize,
+    // usize::MAX は無制限
+    budget: AtomicUsize,
+    evictions: AtomicU64,
+    // 捨てる処理は同時に1つだけ
+    evicting: Mutex<()>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub entries: usize,
+    // 見積もりの使用バイト数
+    pub bytes: usize,
+}
+
+impl CacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl Add for CacheStats {
+    type Output = CacheStats;
+
+    fn add(self, rhs: CacheStats) -> CacheStats {
+        CacheStats {
+            hits: self.hits + rhs.hits,
+            misses: self.misses + rhs.misses,
+            evictions: self.evictions + rhs.evictions,
+            entries: self.entries + rhs.entries,
+            bytes: self.bytes + rhs.bytes,
+        }
+    }
+}
+
+impl<K, V> Default for BoundedCache<K, V>
+where
+    K: Eq + Hash + Clone + CacheWeight,
+    V: Clone + CacheWeight,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> BoundedCache<K, V>
+where
+    K: Eq + Hash + Clone + CacheWeight,
+    V: Clone + CacheWeight,
+{
+    pub fn new() -> Self {
+        BoundedCache {
+            shards: (0..SHARDS).map(|_| Shard::new()).collect(),
+            hasher: RandomState::new(),
+            clock: AtomicU64::new(0),
+            bytes: AtomicUsize::new(0),
+            budget: AtomicUsize::new(usize::MAX),
+            evictions: AtomicU64::new(0),
+            evicting: Mutex::new(()),
+        }
+    }
+
+    pub fn with_budget(budget: Option<usize>) -> Self {
+        let cache = Self::new();
+        cache.set_budget(budget);
+        cache
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        let shard = self.shard(key);
+        let map = shard.map.read().ok()?;
+        let Some(entry) = map.get(key) else {
+            shard.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+        shard.hits.fetch_add(1, Ordering::Relaxed);
+        entry
+            .last_used
+            .store(self.clock.load(Ordering::Relaxed), Ordering::Relaxed);
+        Some(entry.value.clone())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let weight = key.cache_weight() + value.cache_weight() + ENTRY_OVERHEAD;
+        {
+            let Ok(mut map) = self.shard(&key).map.write() else {
+                eprintln!("Failed to acquire write lock for the cache");
+                return;
+            };
+
+            let entry = Entry {
+                value,
+                weight,
+                last_used: AtomicU64::new(self.clock.fetch_add(1, Ordering::Relaxed)),
+            };
+            if let Some(old) = map.insert(key, entry) {
+                self.bytes.fetch_sub(old.weight, Ordering::Relaxed);
+            }
+            self.bytes.fetch_add(weight, Ordering::Relaxed);
+        }
+
+        // シャードのロックを外してから捨てる. 他のスレッドが捨てている最中なら任せる
+        let budget = self.budget.load(Ordering::Relaxed);
+        if self.bytes() > budget {
+            if let Ok(_evicting) = self.evicting.try_lock() {
+                self.evict(budget / EVICT_TO_DENOM * EVICT_TO_NUMER);
+            }
+        }
+    }
+
+    pub fn clear(&self) {
+        for shard in self.shards.iter() {
+            if let Ok(mut map) = shard.map.write() {
+                let weight = map.values().map(|entry| entry.weight).sum::<usize>();
+                map.clear();
+                self.bytes.fetch_sub(weight, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.map.read().map_or(0, |map| map.len()))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // 見積もりの使用バイト数
+    pub fn bytes(&self) -> usize {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn budget(&self) -> Option<usize> {
+        match self.budget.load(Ordering::Relaxed) {
+            usize::MAX => None,
+            budget => Some(budget),
+        }
+    }
+
+    // None で無制限. 今の使用量が新しい予算を超えていれば、すぐに捨てる
+    pub fn set_budget(&self, budget: Option<usize>) {
+        let budget = budget.unwrap_or(usize::MAX);
+        self.budget.store(budget, Ordering::Relaxed);
+
+        if self.bytes() > budget {
+            if let Ok(_evicting) = self.evicting.lock() {
+                self.evict(budget);
+            }
+        }
+    }
+
+    // 永続化用. 値はクローンして返す
+    pub(crate) fn entries(&self) -> Vec<(K, V)> {
+        self.shards
+            .iter()
+            .flat_map(|shard| {
+                shard.map.read().map_or(Vec::new(), |map| {
+                    map.iter()
+                        .map(|(key, entry)| (key.clone(), entry.value.clone()))
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self
+                .shards
+                .iter()
+                .map(|shard| shard.hits.load(Ordering::Relaxed))
+                .sum(),
+            misses: self
+                .shards
+                .iter()
+                .map(|shard| shard.misses.load(Ordering::Relaxed))
+                .sum(),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            entries: self.len(),
+            bytes: self.bytes(),
+        }
+    }
+
+    // カウンターだけを0に戻す. エントリは消さない
+    pub fn reset_stats(&self) {
+        for shard in self.shards.iter() {
+            shard.hits.store(0, Ordering::Relaxed);
+            shard.misses.store(0, Ordering::Relaxed);
+        }
+        self.evictions.store(0, Ordering::Relaxed);
+    }
+
+    fn shard(&self, key: &K) -> &Shard<K, V> {
+        &self.shards[self.hasher.hash_one(key) as usize % SHARDS]
+    }
+
+    // evicting のロックを取ってから呼ぶ
+    fn evict(&self, target: usize) {
+        let mut by_last_used = Vec::new();
+        for (i, shard) in self.shards.iter().enumerate() {
+            if let Ok(map) = shard.map.read() {
+                by_last_used.extend(map.iter().map(|(key, entry)| {
+                    let last_used = entry.last_used.load(Ordering::Relaxed);
+                    (last_used, i, key.clone(), entry.weight)
+                }));
+            }
+        }
+        by_last_used.sort_unstable_by_key(|(last_used, _, _, _)| *last_used);
+
+        // 古いものから、target を下回るまで選んでから、シャードごとにまとめて消す
+        let mut excess = self.bytes().saturating_sub(target);
+        let mut victims = vec![Vec::new(); SHARDS];
+        for (_, i, key, weight) in by_last_used {
+            if excess == 0 {
+                break;
+            }
+            excess = excess.saturating_sub(weight);
+            victims[i].push(key);
+        }
+
+        for (shard, keys) in self.shards.iter().zip(victims) {
+            if keys.is_empty() {
+                continue;
+            }
+            if let Ok(mut map) = shard.map.write() {
+                for key in keys {
+                    if let Some(entry) = map.remove(&key) {
+                        self.bytes.fetch_sub(entry.weight, Ordering::Relaxed);
+                        self.evictions.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub type DealerProbsCache = BoundedCache<Deck, Arc<DealerHandProb>>;
+
+// キャッシュごとのメモリ予算 (バイト). None は無制限
+// Solver は stand_ev と hit_ev を厳密な値と f64 のキャッシュで半分ずつ使う
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheBudget {
+    pub stand_ev: Option<usize>,
+    pub hit_ev: Option<usize>,
+    pub dealer_probs: Option<usize>,
+}
+
+impl CacheBudget {
+    pub fn unbounded() -> Self {
+        CacheBudget::default()
+    }
+
+    // 全体の予算を分ける. hit はエントリが多いので半分、残りを stand と dealer_probs で分ける
+    pub fn total(bytes: usize) -> Self {
+        CacheBudget {
+            stand_ev: Some(bytes / 4),
+            hit_ev: Some(bytes / 2),
+            dealer_probs: Some(bytes / 4),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl CacheWeight for u32 {
+        fn cache_weight(&self) -> usize {
+            size_of::<Self>()
+        }
+    }
+
+    fn entry_weight() -> usize {
+        2 * size_of::<u32>() + ENTRY_OVERHEAD
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let cache = BoundedCache::<u32, u32>::new();
+        for i in 0..1000 {
+            cache.insert(i, i * 2);
+        }
+
+        assert_eq!(cache.len(), 1000);
+        assert_eq!(cache.bytes(), 1000 * entry_weight());
+        assert_eq!(cache.get(&10), Some(20));
+    }
+
+    #[test]
+    fn test_evict_least_recently_used() {
+        let cache = BoundedCache::<u32, u32>::with_budget(Some(10 * entry_weight()));
+        for i in 0..10 {
+            cache.insert(i, i);
+        }
+        // 0 を使っておくと、0 は残る
+        assert_eq!(cache.get(&0), Some(0));
+
+        cache.insert(10, 10);
+
+        assert!(cache.bytes() <= 10 * entry_weight());
+        assert_eq!(cache.get(&0), Some(0));
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&10), Some(10));
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 3);
+        assert_eq!(stats.misses, 1);
+        assert!(stats.evictions > 0);
+        assert_eq!(stats.entries, cache.len());
+
+        cache.reset_stats();
+        assert_eq!(cache.stats().hits, 0);
+        assert_eq!(cache.stats().entries, cache.len());
+    }
+
+    #[test]
+    fn test_concurrent_insert() {
+        use rayon::prelude::*;
+
+        let cache = BoundedCache::<u32, u32>::with_budget(Some(1000 * entry_weight()));
+        (0..10_000u32).into_par_iter().for_each(|i| {
+            cache.insert(i, i);
+            assert!(cache.get(&i).is_none_or(|v| v == i));
+        });
+
+        assert_eq!(cache.bytes(), cache.len() * entry_weight());
+        assert_eq!(cache.stats().evictions as usize, 10_000 - cache.len());
+
+        // 別のスレッドが捨てている間の挿入は、予算を少し超えたまま残ることがある
+        cache.set_budget(Some(1000 * entry_weight()));
+        assert!(cache.len() <= 1000);
+    }
+
+    #[test]
+    fn test_set_budget() {
+        let cache = BoundedCache::<u32, u32>::new();
+        for i in 0..100 {
+            cache.insert(i, i);
+        }
+        cache.set_budget(Some(20 * entry_weight()));
+
+        assert_eq!(cache.len(), 20);
+        assert_eq!(cache.get(&99), Some(99));
+        assert_eq!(cache.get(&0), None);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.bytes(), 0);
+    }
+}
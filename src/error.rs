@@ -0,0 +1,60 @@
+use std::fmt;
+
+// crate 全体のエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    // CancellationToken でキャンセルされた
+    Cancelled,
+    // 期限までに計算が終わらなかった
+    DeadlineExceeded,
+    InvalidDeck(String),
+    InvalidHand(String),
+    UnsupportedRule(String),
+    // 戦略がその場面で選べない行動を返した
+    InvalidAction(String),
+    // onnx のモデルの読み込みや推論の失敗
+    Model(String),
+    // 確率が Ratio<u128> に収まらない (デッキが大きすぎる)
+    Overflow(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Cancelled => write!(f, "calculation was cancelled"),
+            Error::DeadlineExceeded => write!(f, "calculation exceeded its deadline"),
+            Error::InvalidDeck(message) => write!(f, "invalid deck: {}", message),
+            Error::InvalidHand(message) => write!(f, "invalid hand: {}", message),
+            Error::UnsupportedRule(message) => write!(f, "unsupported rule: {}", message),
+            Error::InvalidAction(message) => write!(f, "invalid action: {}", message),
+            Error::Model(message) => write!(f, "model error: {}", message),
+            Error::Overflow(message) => write!(f, "arithmetic overflow: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Error::Cancelled.to_string(), "calculation was cancelled");
+        assert_eq!(
+            Error::InvalidDeck("not enough aces".to_string()).to_string(),
+            "invalid deck: not enough aces"
+        );
+
+        assert_eq!(
+            Error::Overflow("1/3 + 1/5".to_string()).to_string(),
+            "arithmetic overflow: 1/3 + 1/5"
+        );
+
+        let error: Box<dyn std::error::Error> = Box::new(Error::DeadlineExceeded);
+        assert_eq!(error.to_string(), "calculation exceeded its deadline");
+    }
+}
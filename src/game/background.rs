@@ -0,0 +1,50 @@
+use crate::game::EvControl;
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::LazyLock;
+use tokio::sync::oneshot;
+
+// async API 用の専用プール. tokio のワーカーや呼び出し側のグローバルプールを塞がない
+static BACKGROUND_POOL: LazyLock<rayon::ThreadPool> = LazyLock::new(|| {
+    rayon::ThreadPoolBuilder::new()
+        .thread_name(|i| format!("bjc-ev-{}", i))
+        .build()
+        .expect("failed to build the EV thread pool")
+});
+
+// f を専用プールで動かし、終わったら結果を返す
+pub(crate) async fn run<T, F>(control: &EvControl, f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce(&EvControl) -> T + Send + 'static,
+{
+    run_in(&BACKGROUND_POOL, control, f).await
+}
+
+// f を pool で動かす. 中の par_iter も pool で動く
+// 返した future が完了前に drop されたら、control の子トークンをキャンセルして計算を止める
+pub(crate) async fn run_in<T, F>(pool: &rayon::ThreadPool, control: &EvControl, f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce(&EvControl) -> T + Send + 'static,
+{
+    let token = control.cancellation_token().child_token();
+    let cancel_on_drop = token.clone().drop_guard();
+    let control = control.clone().with_cancellation_token(token);
+
+    let (sender, receiver) = oneshot::channel();
+    pool.spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(&control)));
+        // 受け取る側がもういなければ捨てる
+        let _ = sender.send(result);
+    });
+
+    let result = receiver
+        .await
+        .expect("EV worker exited without sending a result");
+    cancel_on_drop.disarm();
+    match result {
+        Ok(value) => value,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
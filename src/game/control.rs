@@ -0,0 +1,146 @@
+use crate::Error;
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+// 進捗の通知. PreRoundPattern 1つ分終わるごとに呼ばれる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub completed: usize,
+    pub total: usize,
+    pub elapsed: Duration,
+    // これまでの1パターンあたりの時間から見積もる. 1つも終わっていなければ None
+    pub estimated_remaining: Option<Duration>,
+}
+
+impl Progress {
+    pub(crate) fn new(completed: usize, total: usize, elapsed: Duration) -> Self {
+        let estimated_remaining =
+            (completed > 0).then(|| elapsed.mul_f64((total - completed) as f64 / completed as f64));
+        Progress {
+            completed,
+            total,
+            elapsed,
+            estimated_remaining,
+        }
+    }
+
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.completed as f64 / self.total as f64
+        }
+    }
+}
+
+type ProgressCallback = Arc<dyn Fn(Progress) + Send + Sync>;
+
+// 計算を打ち切る条件 (キャンセル, 期限) と進捗の通知先
+// コールバックは rayon のワーカースレッドから呼ばれるので、重い処理はしないこと
+#[derive(Clone, Default)]
+pub struct EvControl {
+    token: CancellationToken,
+    deadline: Option<Instant>,
+    progress: Option<ProgressCallback>,
+}
+
+impl EvControl {
+    pub fn new() -> Self {
+        EvControl::default()
+    }
+
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.token = token;
+        self
+    }
+
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    pub fn with_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Progress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    // キャンセルを期限より優先する
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        if self.token.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(Error::DeadlineExceeded),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn report(&self, progress: Progress) {
+        if let Some(callback) = &self.progress {
+            callback(progress);
+        }
+    }
+}
+
+impl From<&CancellationToken> for EvControl {
+    fn from(token: &CancellationToken) -> Self {
+        EvControl::new().with_cancellation_token(token.clone())
+    }
+}
+
+impl fmt::Debug for EvControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EvControl")
+            .field("cancelled", &self.token.is_cancelled())
+            .field("deadline", &self.deadline)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(EvControl::new().check(), Ok(()));
+
+        let expired = EvControl::new().with_deadline(Instant::now());
+        assert_eq!(expired.check(), Err(Error::DeadlineExceeded));
+
+        let token = CancellationToken::new();
+        let control = EvControl::from(&token).with_deadline(Instant::now());
+        token.cancel();
+        assert_eq!(control.check(), Err(Error::Cancelled));
+    }
+
+    #[test]
+    fn test_progress_estimate() {
+        let progress = Progress::new(1, 4, Duration::from_secs(2));
+        assert_eq!(progress.estimated_remaining, Some(Duration::from_secs(6)));
+        assert_eq!(progress.fraction(), 0.25);
+        assert_eq!(
+            Progress::new(0, 4, Duration::ZERO).estimated_remaining,
+            None
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::game::round::ActionEV;
+
+use num::bigint::{BigInt, Sign};
+use num::rational::Ratio;
+use num::{Signed, ToPrimitive, Zero};
+use serde::{Deserialize, Serialize};
+
+// 厳密なEVを digits 桁の小数にする. 四捨五入 (0.5 はゼロから遠い方へ)
+pub fn decimal_string(value: &Ratio<BigInt>, digits: usize) -> String {
+    let scale = BigInt::from(10).pow(digits as u32);
+    let rounded = (value * Ratio::from_integer(scale)).round().to_integer();
+
+    let sign = if rounded.sign() == Sign::Minus {
+        "-"
+    } else {
+        ""
+    };
+    let mut abs_digits = rounded.abs().to_string();
+    if abs_digits.len() <= digits {
+        abs_digits = format!(
+            "{}{}",
+            "0".repeat(digits + 1 - abs_digits.len()),
+            abs_digits
+        );
+    }
+
+    let (integer, fraction) = abs_digits.split_at(abs_digits.len() - digits);
+    if digits == 0 {
+        format!("{}{}", sign, integer)
+    } else {
+        format!("{}{}.{}", sign, integer, fraction)
+    }
+}
+
+// パーセント表記. -0.005 → "-0.50%" (digits = 2)
+pub fn percent_string(value: &Ratio<BigInt>, digits: usize) -> String {
+    format!("{}%", decimal_string(&(value * BigInt::from(100)), digits))
+}
+
+// ベーシスポイント (1bp = 0.01%)
+pub fn basis_points_string(value: &Ratio<BigInt>, digits: usize) -> String {
+    decimal_string(&(value * BigInt::from(10_000)), digits)
+}
+
+pub fn to_f64(value: &Ratio<BigInt>) -> f64 {
+    // to_f64 は分子と分母が大きくても丸めて計算する
+    value.to_f64().unwrap_or(if value.is_zero() {
+        0.0
+    } else if value.is_negative() {
+        f64::NEG_INFINITY
+    } else {
+        f64::INFINITY
+    })
+}
+
+// 厳密な分数と、それを丸めた小数を並べたもの. レポートの出力用
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvDecimal {
+    pub numer: String,
+    pub denom: String,
+    pub decimal: String,
+    pub percent: String,
+    pub basis_points: String,
+    pub f64: f64,
+}
+
+impl EvDecimal {
+    pub fn new(value: &Ratio<BigInt>, digits: usize) -> Self {
+        EvDecimal {
+            numer: value.numer().to_string(),
+            denom: value.denom().to_string(),
+            decimal: decimal_string(value, digits),
+            percent: percent_string(value, digits),
+            basis_points: basis_points_string(value, digits),
+            f64: to_f64(value),
+        }
+    }
+
+    // 文字列が数として読めない場合は None
+    pub fn exact(&self) -> Option<Ratio<BigInt>> {
+        let numer = self.numer.parse::<BigInt>().ok()?;
+        let denom = self.denom.parse::<BigInt>().ok()?;
+        if denom.is_zero() {
+            return None;
+        }
+        Some(Ratio::new(numer, denom))
+    }
+}
+
+impl ActionEV {
+    pub fn to_decimal(&self, digits: usize) -> ActionEV<EvDecimal> {
+        let decimal = |ev: &Ratio<BigInt>| EvDecimal::new(ev, digits);
+        ActionEV {
+            stand: decimal(&self.stand),
+            hit: self.hit.as_ref().map(decimal),
+            double: self.double.as_ref().map(decimal),
+            split: self.split.as_ref().map(decimal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(numer: i64, denom: i64) -> Ratio<BigInt> {
+        Ratio::new(BigInt::from(numer), BigInt::from(denom))
+    }
+
+    #[test]
+    fn test_decimal_string() {
+        assert_eq!(decimal_string(&ratio(1, 3), 4), "0.3333");
+        assert_eq!(decimal_string(&ratio(2, 3), 4), "0.6667");
+        assert_eq!(decimal_string(&ratio(-2, 3), 4), "-0.6667");
+        assert_eq!(decimal_string(&ratio(1, 8), 2), "0.13");
+        assert_eq!(decimal_string(&ratio(-1, 8), 2), "-0.13");
+        assert_eq!(decimal_string(&ratio(3, 2), 0), "2");
+        assert_eq!(decimal_string(&ratio(7, 1), 3), "7.000");
+        // 丸めて0になる場合は符号をつけない
+        assert_eq!(decimal_string(&ratio(-1, 1000), 2), "0.00");
+    }
+
+    #[test]
+    fn test_percent_and_basis_points() {
+        // 8デッキの pre_round の値
+        let ev = Ratio::new(
+            "-1243148184227145034346117698114444483224258977"
+                .parse()
+                .unwrap(),
+            "221172350277495373076549362381539684887036067525"
+                .parse()
+                .unwrap(),
+        );
+
+        assert_eq!(percent_string(&ev, 3), "-0.562%");
+        assert_eq!(basis_points_string(&ev, 1), "-56.2");
+        assert!((to_f64(&ev) + 0.005620).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_ev_decimal_serde() {
+        let value = ratio(-1, 200);
+        let ev = EvDecimal::new(&value, 2);
+        let json = serde_json::to_string(&ev).unwrap();
+
+        assert!(json.contains("\"percent\":\"-0.50%\""), "{}", json);
+        let parsed: EvDecimal = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.exact().unwrap(), value);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::cache::CacheWeight;
+use crate::game::BlackJackGame;
+use crate::models::{Card, Deck, Hand, HandTotal, Rule};
+
+use std::hash::{Hash, Hasher};
+use std::mem::size_of;
+use std::sync::Arc;
+
+// 手札の合計のうち、数字でないもの
+const TOTAL_BLACKJACK: u64 = 62;
+const TOTAL_BURST: u64 = 63;
+
+// チャーリーで見るのは6枚以上かどうかだけ
+const CHARLIE_CARDS: usize = 6;
+
+// キャッシュのキー. deck, アップカード, 手札, 枚数を [u64; 3] に詰める
+// 枚数は Rule が枚数を見ないときは 0 にそろえるので、同じ状態は同じエントリになる
+// rule は Arc の比較で済むことが多いので、ハッシュには入れない
+#[derive(Debug, Clone)]
+pub struct GameKey {
+    rule: Arc<Rule>,
+    packed: PackedKey,
+}
+
+// 詰められない状態 (1つのランクが 65535 枚を超えるシューや、範囲外の合計) はそのまま持つ
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PackedKey {
+    Small([u64; 3]),
+    Wide(Box<WideKey>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct WideKey {
+    deck_cards: Deck,
+    dealer_card: Card,
+    player_hand: Hand,
+    player_card_count: usize,
+}
+
+impl GameKey {
+    pub fn new(game: &BlackJackGame) -> Self {
+        let card_count = canonical_card_count(&game.rule, game.player_card_count);
+        let packed = match pack(game, card_count) {
+            Some(packed) => PackedKey::Small(packed),
+            None => PackedKey::Wide(Box::new(WideKey {
+                deck_cards: game.deck_cards.clone(),
+                dealer_card: game.dealer_card,
+                player_hand: game.player_hand.clone(),
+                player_card_count: card_count,
+            })),
+        };
+
+        GameKey {
+            rule: Arc::clone(&game.rule),
+            packed,
+        }
+    }
+
+    pub fn rule(&self) -> &Arc<Rule> {
+        &self.rule
+    }
+
+    // 枚数は canonical なものに置き換わる
+    pub fn to_game(&self) -> BlackJackGame {
+        let packed = match &self.packed {
+            PackedKey::Small(packed) => packed,
+            PackedKey::Wide(wide) => {
+                return BlackJackGame {
+                    rule: Arc::clone(&self.rule),
+                    dealer_card: wide.dealer_card,
+                    player_hand: wide.player_hand.clone(),
+                    deck_cards: wide.deck_cards.clone(),
+                    player_card_count: wide.player_card_count,
+                }
+            }
+        };
+        let field = |word: usize, shift: u32, bits: u32| {
+            ((packed[word] >> shift) & ((1 << bits) - 1)) as usize
+        };
+
+        let mut deck_cards = Deck::zero();
+        for (i, card) in Card::ALL.into_iter().enumerate() {
+            for _ in 0..field(i / 4, (i % 4) as u32 * 16, 16) {
+                deck_cards.add_mut(card);
+            }
+        }
+
+        BlackJackGame {
+            rule: Arc::clone(&self.rule),
+            dealer_card: Card::ALL[field(2, 32, 8)],
+            player_hand: unpack_hand(field(2, 40, 8) as u64),
+            deck_cards,
+            player_card_count: field(2, 48, 8),
+        }
+    }
+}
+
+// 収まらなければ None
+fn pack(game: &BlackJackGame, card_count: usize) -> Option<[u64; 3]> {
+    let mut deck = [0u64; 10];
+    for (packed, count) in deck.iter_mut().zip(game.deck_cards.to_array()) {
+        *packed = u16::try_from(count).ok()? as u64;
+    }
+    let hand = pack_hand(&game.player_hand)?;
+    let card_count = card_count as u64;
+
+    Some([
+        deck[0] | deck[1] << 16 | deck[2] << 32 | deck[3] << 48,
+        deck[4] | deck[5] << 16 | deck[6] << 32 | deck[7] << 48,
+        deck[8]
+            | deck[9] << 16
+            | card_index(game.dealer_card) << 32
+            | hand << 40
+            | card_count << 48,
+    ])
+}
+
+impl PartialEq for GameKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.packed == other.packed
+            && (Arc::ptr_eq(&self.rule, &other.rule) || self.rule == other.rule)
+    }
+}
+
+impl Eq for GameKey {}
+
+impl Hash for GameKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.packed.hash(state);
+    }
+}
+
+impl CacheWeight for GameKey {
+    fn cache_weight(&self) -> usize {
+        // rule は Arc で共有しているので数えない
+        match &self.packed {
+            PackedKey::Small(_) => size_of::<Self>(),
+            PackedKey::Wide(_) => size_of::<Self>() + size_of::<WideKey>(),
+        }
+    }
+}
+
+impl BlackJackGame {
+    pub fn cache_key(&self) -> GameKey {
+        GameKey::new(self)
+    }
+}
+
+fn canonical_card_count(rule: &Rule, player_card_count: usize) -> usize {
+    if rule.six_card_charlie {
+        player_card_count.min(CHARLIE_CARDS)
+    } else {
+        0
+    }
+}
+
+fn card_index(card: Card) -> u64 {
+    Card::ALL.iter().position(|&c| c == card).unwrap() as u64
+}
+
+// 上位2ビットが種類、下位6ビットが合計 (ペアはカード). 合計が6ビットに収まらなければ None
+fn pack_hand(hand: &Hand) -> Option<u64> {
+    let pack_total = |total: &HandTotal| match total {
+        HandTotal::Value(value) => Some(*value as u64).filter(|&value| value < TOTAL_BLACKJACK),
+        HandTotal::BlackJack => Some(TOTAL_BLACKJACK),
+        HandTotal::Burst => Some(TOTAL_BURST),
+    };
+
+    Some(match hand {
+        Hand::None => 0,
+        Hand::Soft(total) => 1 << 6 | pack_total(total)?,
+        Hand::Hard(total) => 2 << 6 | pack_total(total)?,
+        Hand::Pair(card) => 3 << 6 | card_index(*card),
+    })
+}
+
+fn unpack_hand(packed: u64) -> Hand {
+    let total = match packed & 0x3f {
+        TOTAL_BLACKJACK => HandTotal::BlackJack,
+        TOTAL_BURST => HandTotal::Burst,
+        value => HandTotal::Value(value as usize),
+    };
+
+    match packed >> 6 {
+        1 => Hand::Soft(total),
+        2 => Hand::Hard(total),
+        3 => Hand::Pair(Card::ALL[(packed & 0x3f) as usize]),
+        _ => Hand::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(rule: Rule, player_hand: Hand, player_card_count: usize) -> BlackJackGame {
+        BlackJackGame::new(
+            rule,
+            Card::N7,
+            player_hand,
+            Deck::new(8).remove_deck(&Deck::new_from_strs(&vec!["7", "A", "5", "T"])),
+            player_card_count,
+        )
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for hand in [
+            Hand::Hard(HandTotal::Value(16)),
+            Hand::Soft(HandTotal::Value(18)),
+            Hand::Hard(HandTotal::Burst),
+            Hand::Soft(HandTotal::BlackJack),
+            Hand::Pair(Card::Ace),
+            Hand::Pair(Card::Face),
+        ] {
+            let mut rule = Rule::evolution_classic();
+            rule.six_card_charlie = true;
+            let game = game(rule, hand, 3);
+
+            assert_eq!(game.cache_key().to_game(), game);
+        }
+    }
+
+    #[test]
+    fn test_card_count_dropped_without_charlie() {
+        let hand = Hand::Hard(HandTotal::Value(14));
+        let rule = Rule::evolution_classic();
+        assert_eq!(
+            game(rule.clone(), hand.clone(), 2).cache_key(),
+            game(rule, hand.clone(), 4).cache_key()
+        );
+
+        let mut charlie = Rule::evolution_classic();
+        charlie.six_card_charlie = true;
+        assert_ne!(
+            game(charlie.clone(), hand.clone(), 2).cache_key(),
+            game(charlie.clone(), hand.clone(), 4).cache_key()
+        );
+        // 6枚以上は同じ
+        assert_eq!(
+            game(charlie.clone(), hand.clone(), 6).cache_key(),
+            game(charlie, hand, 7).cache_key()
+        );
+    }
+
+    #[test]
+    fn test_rule_distinguishes_keys() {
+        let hand = Hand::Hard(HandTotal::Value(14));
+        let mut peek = Rule::evolution_classic();
+        peek.dealer_peek = true;
+
+        assert_ne!(
+            game(Rule::evolution_classic(), hand.clone(), 2).cache_key(),
+            game(peek, hand.clone(), 2).cache_key()
+        );
+        // 別の Arc でも中身が同じなら同じキー
+        assert_eq!(
+            game(Rule::evolution_classic(), hand.clone(), 2).cache_key(),
+            game(Rule::evolution_classic(), hand, 2).cache_key()
+        );
+    }
+
+    #[test]
+    fn test_wide_key() {
+        // 1つのランクが u16 に収まらないシューと、6ビットに収まらない合計
+        let mut deck = Deck::zero();
+        for _ in 0..=u16::MAX as usize {
+            deck.add_mut(Card::Face);
+        }
+        deck.add_mut(Card::N2);
+        let huge = BlackJackGame::new(
+            Rule::evolution_classic(),
+            Card::N7,
+            Hand::Hard(HandTotal::Value(14)),
+            deck.clone(),
+            2,
+        );
+        let key = huge.cache_key();
+        assert!(matches!(key.packed, PackedKey::Wide(_)));
+        assert_eq!(key.to_game().deck_cards, deck);
+        assert_eq!(key, huge.cache_key());
+        let fewer = BlackJackGame {
+            deck_cards: deck.remove(Card::N2),
+            ..huge.clone()
+        };
+        assert_ne!(key, fewer.cache_key());
+        assert!(key.cache_weight() > size_of::<GameKey>());
+
+        let out_of_range = game(
+            Rule::evolution_classic(),
+            Hand::Hard(HandTotal::Value(100)),
+            2,
+        );
+        assert_eq!(
+            out_of_range.cache_key().to_game().player_hand,
+            out_of_range.player_hand
+        );
+    }
+}
@@ -1,5 +1,16 @@
+mod background;
+pub mod control;
+pub mod decimal;
+pub mod key;
+pub mod number;
 pub mod pre_round;
 pub mod round;
+pub mod solver;
 
-pub use pre_round::PreBlackJackGame;
-pub use round::BlackJackGame;
+pub use control::{EvControl, Progress};
+pub use decimal::EvDecimal;
+pub use key::GameKey;
+pub use number::EvNumber;
+pub use pre_round::{PartialEv, PreBlackJackGame};
+pub use round::{Action, ActionEV, BlackJackGame};
+pub use solver::{Phase, PhaseStats, Solver, SolverStats};
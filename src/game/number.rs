@@ -0,0 +1,80 @@
+use crate::cache::{BoundedCache, CacheWeight};
+use crate::game::{decimal, GameKey, Solver};
+
+use num::rational::Ratio;
+use num::BigInt;
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, Mul, Sub};
+
+// EVの計算に使う数値型
+// Ratio<BigInt> は厳密だが遅い. f64 は速いが誤差がある
+pub trait EvNumber:
+    Clone
+    + Debug
+    + PartialOrd
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Sum
+    + CacheWeight
+{
+    fn from_integer(n: i64) -> Self;
+
+    fn from_ratio(numer: u128, denom: u128) -> Self;
+
+    // 表示用. Ratio<BigInt> は丸める
+    fn as_f64(&self) -> f64;
+
+    // 数値型ごとに Solver の別のキャッシュを使う
+    fn stand_cache(solver: &Solver) -> &BoundedCache<GameKey, Self>;
+
+    fn hit_cache(solver: &Solver) -> &BoundedCache<GameKey, Self>;
+}
+
+impl EvNumber for Ratio<BigInt> {
+    fn from_integer(n: i64) -> Self {
+        Ratio::from_integer(BigInt::from(n))
+    }
+
+    fn from_ratio(numer: u128, denom: u128) -> Self {
+        Ratio::new(BigInt::from(numer), BigInt::from(denom))
+    }
+
+    fn as_f64(&self) -> f64 {
+        decimal::to_f64(self)
+    }
+
+    fn stand_cache(solver: &Solver) -> &BoundedCache<GameKey, Self> {
+        &solver.exact_stand_ev
+    }
+
+    fn hit_cache(solver: &Solver) -> &BoundedCache<GameKey, Self> {
+        &solver.exact_hit_ev
+    }
+}
+
+impl EvNumber for f64 {
+    fn from_integer(n: i64) -> Self {
+        n as f64
+    }
+
+    fn from_ratio(numer: u128, denom: u128) -> Self {
+        numer as f64 / denom as f64
+    }
+
+    fn as_f64(&self) -> f64 {
+        *self
+    }
+
+    fn stand_cache(solver: &Solver) -> &BoundedCache<GameKey, Self> {
+        &solver.f64_stand_ev
+    }
+
+    fn hit_cache(solver: &Solver) -> &BoundedCache<GameKey, Self> {
+        &solver.f64_hit_ev
+    }
+}
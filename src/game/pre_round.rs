@@ -1,16 +1,49 @@
-use crate::game::BlackJackGame;
+use crate::game::{background, BlackJackGame, EvControl, EvNumber, Progress, Solver, SolverStats};
+use crate::models::deck::checked_mul;
 use crate::models::{Card, Deck, HandTotal, PreRoundPattern, Rule};
+use crate::Error;
 
 use num::bigint::BigInt;
 use num::rational::Ratio;
 use num::ToPrimitive;
-use num::Zero;
 use std::collections::HashMap;
 
 use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio_util::sync::CancellationToken;
 
+// 途中までの計算結果. lower..=upper に最終的なEVが入る
+// 最後まで計算できたときは interrupted が None で、lower == upper == ev
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialEv<N = Ratio<BigInt>> {
+    pub completed: usize,
+    pub total: usize,
+    // 計算が終わったパターンの確率の和
+    pub evaluated_mass: N,
+    // 計算が終わったパターンの (確率で重み付けした) EVの和
+    pub ev: N,
+    pub lower: N,
+    pub upper: N,
+    // 止まった理由. 最初に見つかったもの
+    pub interrupted: Option<Error>,
+}
+
+impl<N: EvNumber> PartialEv<N> {
+    pub fn is_complete(&self) -> bool {
+        self.interrupted.is_none()
+    }
+
+    pub fn into_result(self) -> Result<N, Error> {
+        match self.interrupted {
+            None => Ok(self.ev),
+            Some(e) => Err(e),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct PreBlackJackGame {
     rule: Arc<Rule>,
     deck: Deck,
@@ -24,6 +57,15 @@ impl PreBlackJackGame {
         }
     }
 
+    // BlackJackGame と同じデフォルトの Solver の統計
+    pub fn stats() -> SolverStats {
+        Solver::global().stats()
+    }
+
+    pub fn reset_stats() {
+        Solver::global().reset_stats();
+    }
+
     pub fn calc_ev(&self) -> Ratio<BigInt> {
         let token = CancellationToken::new();
         self.calc_ev_with_cancellation_token(&token).unwrap()
@@ -34,53 +76,232 @@ impl PreBlackJackGame {
         self.ev_with_cancellation_token(&token).unwrap()
     }
 
+    // 数値型を指定して計算する. f64 なら厳密ではないが速い
+    pub fn ev_as<N: EvNumber>(&self) -> N {
+        let token = CancellationToken::new();
+        self.calc_ev_with_cancellation_token_as(&token).unwrap()
+    }
+
     pub fn ev_with_cancellation_token(
         &self,
         cancellation_token: &CancellationToken,
-    ) -> Result<Ratio<BigInt>, ()> {
+    ) -> Result<Ratio<BigInt>, Error> {
         let ev = self.calc_ev_with_cancellation_token(cancellation_token)?;
         Ok(ev)
     }
 
     #[cfg(feature = "onnx")]
-    pub fn ml_ev(&self) -> Result<f32, ()> {
-        let input_data = self.input_onnx();
+    pub fn ml_ev(&self) -> Result<f32, Error> {
+        let model_error = |e: ort::Error| Error::Model(e.to_string());
+        let input_data = self.input_onnx()?;
 
         let model_path = include_bytes!("../bjc.onnx");
 
-        let inputs = ort::inputs!["features" => input_data].unwrap();
+        let inputs = ort::inputs!["features" => input_data].map_err(model_error)?;
         // println!("inputs: {:?}", self.input_onnx());
 
         let session = ort::Session::builder()
-            .unwrap()
+            .map_err(model_error)?
             .commit_from_memory(model_path)
-            .unwrap();
+            .map_err(model_error)?;
 
-        let outputs = session.run(inputs).unwrap();
+        let outputs = session.run(inputs).map_err(model_error)?;
         // モデルのセッションを作成
         let scaled_ev = outputs["predictions"]
             .try_extract_tensor::<f32>()
-            .unwrap()
+            .map_err(model_error)?
             .to_owned()[[0, 0]];
 
         Ok(PreBlackJackGame::ev_unscaled(scaled_ev))
     }
 
+    // 期限と進捗の通知を指定して計算する. 期限を過ぎたら Error::DeadlineExceeded
+    pub fn ev_with_control<N: EvNumber>(&self, control: &EvControl) -> Result<N, Error> {
+        self.calc_ev_in(Solver::global(), control)
+    }
+
     pub fn calc_ev_with_cancellation_token(
         &self,
         cancellation_token: &CancellationToken,
-    ) -> Result<Ratio<BigInt>, ()> {
-        PreRoundPattern::all()
+    ) -> Result<Ratio<BigInt>, Error> {
+        self.calc_ev_with_cancellation_token_as(cancellation_token)
+    }
+
+    pub fn calc_ev_with_cancellation_token_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<N, Error> {
+        self.calc_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
+
+    pub(crate) fn calc_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        self.partial_ev_in(solver, control).into_result()
+    }
+
+    // 途中で止まっても、終わったパターンの分は PartialEv にして返す
+    pub fn partial_ev_with_control<N: EvNumber>(&self, control: &EvControl) -> PartialEv<N> {
+        self.partial_ev_in(Solver::global(), control)
+    }
+
+    // 専用のスレッドプールで計算する. future を drop するとキャンセルされる
+    // デフォルトの Solver を使う. Solver やプールを選ぶときは Solver::ev_async など
+    pub async fn ev_async(&self) -> Result<Ratio<BigInt>, Error> {
+        self.ev_with_control_async(&EvControl::new()).await
+    }
+
+    pub async fn ev_with_control_async<N: EvNumber>(
+        &self,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        let game = self.clone();
+        background::run(control, move |control| {
+            game.calc_ev_in(Solver::global(), control)
+        })
+        .await
+    }
+
+    pub async fn partial_ev_async<N: EvNumber>(&self, control: &EvControl) -> PartialEv<N> {
+        let game = self.clone();
+        background::run(control, move |control| {
+            game.partial_ev_in(Solver::global(), control)
+        })
+        .await
+    }
+
+    pub fn partial_ev_with_cancellation_token(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> PartialEv {
+        self.partial_ev_with_control(&EvControl::from(cancellation_token))
+    }
+
+    pub(crate) fn partial_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> PartialEv<N> {
+        self.partial_ev_with(solver, control, |game| game.ev_in(solver, control))
+    }
+
+    // 配られた後の1ハンドのEVを game_ev で計算して、全パターンを足し合わせる
+    // game_ev を変えれば、最適でない戦略のEVも同じ流れで計算できる
+    pub(crate) fn partial_ev_with<N, F>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+        game_ev: F,
+    ) -> PartialEv<N>
+    where
+        N: EvNumber,
+        F: Fn(&BlackJackGame) -> Result<N, Error> + Sync,
+    {
+        let patterns = PreRoundPattern::all();
+        let started = Instant::now();
+        let completed = AtomicUsize::new(0);
+
+        // 配られる確率が u128 に収まらないdeckでは、どのパターンも重み付けできない
+        let probs = match patterns
+            .iter()
+            .map(|pattern| self.pattern_probability(pattern))
+            .collect::<Result<Vec<_>, Error>>()
+        {
+            Ok(probs) => probs,
+            Err(e) => return self.unevaluated(patterns, e),
+        };
+
+        // エラーで打ち切らず、止まった後のパターンもすぐ Err で返して全部集める
+        let results = patterns
             .par_iter()
             .map(|pattern| {
-                if cancellation_token.is_cancelled() {
-                    return Err(());
-                }
-                let ev = self.pre_round_ev_with_cancellation_token(&pattern, cancellation_token)?;
+                control.check()?;
+                let ev = self.pattern_ev_with(solver, pattern, &game_ev)?;
+                let completed = completed.fetch_add(1, Ordering::Relaxed) + 1;
+                control.report(Progress::new(completed, patterns.len(), started.elapsed()));
                 Ok(ev)
             })
-            .collect::<Result<Vec<Ratio<BigInt>>, ()>>()
-            .map(|evs| evs.iter().sum())
+            .collect::<Vec<Result<N, Error>>>();
+
+        let mut partial = PartialEv {
+            completed: 0,
+            total: patterns.len(),
+            evaluated_mass: N::from_integer(0),
+            ev: N::from_integer(0),
+            lower: N::from_integer(0),
+            upper: N::from_integer(0),
+            interrupted: None,
+        };
+        for ((pattern, prob), result) in patterns.iter().zip(probs).zip(results) {
+            let prob = N::from_ratio(*prob.numer(), *prob.denom());
+            match result {
+                Ok(ev) => {
+                    partial.completed += 1;
+                    partial.evaluated_mass = partial.evaluated_mass + prob;
+                    partial.ev = partial.ev + ev;
+                }
+                Err(e) => {
+                    let (lower, upper) = self.pattern_ev_range::<N>(pattern);
+                    partial.lower = partial.lower + lower * prob.clone();
+                    partial.upper = partial.upper + upper * prob;
+                    partial.interrupted.get_or_insert(e);
+                }
+            }
+        }
+        partial.lower = partial.lower + partial.ev.clone();
+        partial.upper = partial.upper + partial.ev.clone();
+        partial
+    }
+
+    fn pattern_probability(
+        &self,
+        pre_round_pattern: &PreRoundPattern,
+    ) -> Result<Ratio<u128>, Error> {
+        checked_mul(
+            &self
+                .deck
+                .deck_draw_probability(&pre_round_pattern.all_deck)?,
+            &Ratio::from(pre_round_pattern.weight as u128),
+        )
+    }
+
+    // 1パターンも計算できなかったときの結果. 範囲は全パターンの pattern_ev_range を合わせたもの
+    fn unevaluated<N: EvNumber>(&self, patterns: &[PreRoundPattern], e: Error) -> PartialEv<N> {
+        let (lower, upper) = patterns
+            .iter()
+            .map(|pattern| self.pattern_ev_range::<N>(pattern))
+            .reduce(|(lower, upper), (l, u)| {
+                (
+                    if l < lower { l } else { lower },
+                    if u > upper { u } else { upper },
+                )
+            })
+            .unwrap_or((N::from_integer(0), N::from_integer(0)));
+        PartialEv {
+            completed: 0,
+            total: patterns.len(),
+            evaluated_mass: N::from_integer(0),
+            ev: N::from_integer(0),
+            lower,
+            upper,
+            interrupted: Some(e),
+        }
+    }
+
+    // 1ハンドのEVが取りうる範囲. スプリットは1回 (2ハンド) まで、スプリット後のダブルはなく、
+    // BJ 以外の倍率は Rule::validate で 1 に限っているので、BJ 以外は -2..=2
+    fn pattern_ev_range<N: EvNumber>(&self, pre_round_pattern: &PreRoundPattern) -> (N, N) {
+        if pre_round_pattern.player_hand.is_blackjack() {
+            let blackjack = self.rule.multiplier_black_jack;
+            (
+                N::from_integer(0),
+                N::from_ratio(*blackjack.numer() as u128, *blackjack.denom() as u128),
+            )
+        } else {
+            (N::from_integer(-2), N::from_integer(2))
+        }
     }
 
     // dealerのそれになる確率を返す
@@ -92,17 +313,17 @@ impl PreBlackJackGame {
     // HandTotal::Value(21),
     // HandTotal::Burst,
     // pub dealer_prob(&self)
-    pub fn dealer_prob(&self) -> [f64; 7] {
-        let binding = self.deck.dealer_probs();
+    // 特徴量用なので f64 で足す. 確率が u128 に収まらないdeckは Error::Overflow
+    pub fn dealer_prob(&self) -> Result<[f64; 7], Error> {
+        let binding = self.deck.dealer_probs()?;
         let mut tmp_probs = HashMap::new();
 
         for card in Card::ALL {
             let card_probs = binding.get(card);
             for (total, prob) in card_probs.iter() {
-                // le tmp_probs.entry(total).or_insert(0.0)
-                let entry = tmp_probs.entry(total).or_insert(Ratio::zero());
+                let entry = tmp_probs.entry(total).or_insert(0.0);
                 let draw_prob = self.deck.draw_probability(card);
-                *entry += prob * Ratio::new(*draw_prob.numer() as u128, *draw_prob.denom() as u128);
+                *entry += prob.to_f64().unwrap_or(0.0) * draw_prob.to_f64().unwrap_or(0.0);
             }
         }
 
@@ -119,15 +340,13 @@ impl PreBlackJackGame {
 
         let mut probs = [0.0; 7];
         for (i, total) in totals.iter().enumerate() {
-            probs[i] = tmp_probs
-                .get(&total)
-                .map_or(0.0, |prob| prob.to_f64().unwrap_or(0.0));
+            probs[i] = tmp_probs.get(&total).copied().unwrap_or(0.0);
         }
-        probs
+        Ok(probs)
     }
 
     #[cfg(feature = "onnx")]
-    pub fn input_onnx(&self) -> ArrayBase<OwnedRepr<f32>, Dim<[usize; 2]>> {
+    pub fn input_onnx(&self) -> Result<ArrayBase<OwnedRepr<f32>, Dim<[usize; 2]>>, Error> {
         let mut output = Vec::new();
         let deck_counts = self.deck.total_cards();
         let deck_maisu = self
@@ -138,7 +357,7 @@ impl PreBlackJackGame {
             .collect::<Vec<f32>>();
 
         let deck_counts = self.deck.total_cards() as f32 / 416 as f32;
-        let dealer_probs = self.dealer_prob();
+        let dealer_probs = self.dealer_prob()?;
 
         output.extend(deck_maisu.into_iter().map(|count| count));
         output.push(deck_counts as f32);
@@ -149,35 +368,132 @@ impl PreBlackJackGame {
         let input_data: ArrayBase<OwnedRepr<f32>, Dim<[usize; 2]>> =
             Array2::from_shape_vec((1, 18), output).unwrap();
 
-        input_data
+        Ok(input_data)
     }
 
     pub fn pre_round_ev_with_cancellation_token(
         &self,
         pre_round_pattern: &PreRoundPattern,
         cancellation_token: &CancellationToken,
-    ) -> Result<Ratio<BigInt>, ()> {
-        let prob = self.deck.deck_draw_probability(&pre_round_pattern.all_deck)
-            * pre_round_pattern.weight as u128;
+    ) -> Result<Ratio<BigInt>, Error> {
+        self.pre_round_ev_with_cancellation_token_as(pre_round_pattern, cancellation_token)
+    }
+
+    pub fn pre_round_ev_with_cancellation_token_as<N: EvNumber>(
+        &self,
+        pre_round_pattern: &PreRoundPattern,
+        cancellation_token: &CancellationToken,
+    ) -> Result<N, Error> {
+        self.pre_round_ev_in(
+            Solver::global(),
+            pre_round_pattern,
+            &EvControl::from(cancellation_token),
+        )
+    }
+
+    pub(crate) fn pre_round_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        pre_round_pattern: &PreRoundPattern,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        self.pattern_ev_with(solver, pre_round_pattern, |game| {
+            game.ev_in(solver, control)
+        })
+    }
+
+    fn pattern_ev_with<N, F>(
+        &self,
+        solver: &Solver,
+        pre_round_pattern: &PreRoundPattern,
+        game_ev: F,
+    ) -> Result<N, Error>
+    where
+        N: EvNumber,
+        F: Fn(&BlackJackGame) -> Result<N, Error>,
+    {
+        let prob = self.pattern_probability(pre_round_pattern)?;
+
+        // このデッキからは配られないパターン (確率0)
+        let Some(black_jack_game) = self.dealt_game(pre_round_pattern) else {
+            return Ok(N::from_integer(0));
+        };
+        let mut ev = game_ev(&black_jack_game)?;
+
+        // peekありのとき、BlackJackGameのEVはディーラーがBJでない条件付きなので、
+        // BJのとき (プレイヤーもBJならpush, それ以外は元のベットだけ負け) を足し戻す
+        if self.rule.dealer_peek {
+            let dealer_blackjack =
+                self.dealer_blackjack_probability::<N>(solver, &black_jack_game)?;
+            let blackjack_ev = if pre_round_pattern.player_hand.is_blackjack() {
+                N::from_integer(0)
+            } else {
+                N::from_integer(-1)
+            };
+            ev = (N::from_integer(1) - dealer_blackjack.clone()) * ev
+                + dealer_blackjack * blackjack_ev;
+        }
+
+        Ok(ev * N::from_ratio(*prob.numer(), *prob.denom()))
+    }
 
-        let deck_cards = self.deck.remove_deck(&pre_round_pattern.all_deck);
+    // 配られた後の場面と、プレイヤーが行動する確率の組. 配られないパターンは除く
+    // peekありでディーラーがBJのときはプレイヤーは行動しない
+    pub(crate) fn dealt_games<N: EvNumber>(
+        &self,
+        solver: &Solver,
+    ) -> Result<Vec<(BlackJackGame, N)>, Error> {
+        let mut dealt_games = Vec::new();
+        for pattern in PreRoundPattern::all() {
+            let Some(game) = self.dealt_game(pattern) else {
+                continue;
+            };
+            let prob = self.pattern_probability(pattern)?;
+            let mut prob = N::from_ratio(*prob.numer(), *prob.denom());
+            if self.rule.dealer_peek {
+                prob = prob
+                    * (N::from_integer(1) - self.dealer_blackjack_probability(solver, &game)?);
+            }
+            dealt_games.push((game, prob));
+        }
+        Ok(dealt_games)
+    }
 
-        let black_jack_game = BlackJackGame {
+    fn dealt_game(&self, pre_round_pattern: &PreRoundPattern) -> Option<BlackJackGame> {
+        let deck_cards = self
+            .deck
+            .try_remove_deck(&pre_round_pattern.all_deck)
+            .ok()?;
+        Some(BlackJackGame {
             rule: Arc::clone(&self.rule),
             dealer_card: pre_round_pattern.dealer_card,
             player_hand: pre_round_pattern.player_hand.clone(),
-            deck_cards: deck_cards.clone(),
+            deck_cards,
             player_card_count: 2,
-        };
-        let ev = black_jack_game.ev_with_cancellation_token(cancellation_token)?;
+        })
+    }
 
-        Ok(ev * Ratio::new(BigInt::from(*prob.numer()), BigInt::from(*prob.denom())))
+    fn dealer_blackjack_probability<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        game: &BlackJackGame,
+    ) -> Result<N, Error> {
+        let dealer_blackjack = solver
+            .dealer_probs(&game.deck_cards)?
+            .get(game.dealer_card)
+            .black_jack;
+        Ok(N::from_ratio(
+            *dealer_blackjack.numer(),
+            *dealer_blackjack.denom(),
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::decimal;
+    use crate::game::ActionEV;
     use crate::models::Hand;
     use std::time::Duration;
     use tokio::time::Instant;
@@ -189,11 +505,12 @@ mod tests {
         let pre_round = PreBlackJackGame::new(rule, deck.clone());
         assert_eq!(
             pre_round.ev(),
+            // スプリットした A と 10 はBJではなく 21 として払う
             Ratio::new(
-                "-1243148184227145034346117698114444483224258977"
+                "-24100748238800970047633936581469332073361788"
                     .parse()
                     .unwrap(),
-                "221172350277495373076549362381539684887036067525"
+                "3150603280306201895677341344466377277593106375"
                     .parse()
                     .unwrap()
             )
@@ -202,12 +519,159 @@ mod tests {
         // 並列化時
         //  numer: 1016350091974324226323438723196054028, denom: 93112157693523724162642086744350091091
     }
+    #[test]
+    fn test_ev_as_f64() {
+        let deck = Deck::new_from_strs(&vec!["A", "2", "3", "4", "5", "6", "7", "8", "9", "10"]);
+        for dealer_peek in [false, true] {
+            let rule = Rule {
+                dealer_peek,
+                ..Rule::evolution_classic()
+            };
+            let pre_round = PreBlackJackGame::new(rule, deck.clone());
+            let ev = pre_round.ev_as::<f64>();
+            let exact = decimal::to_f64(&pre_round.ev());
+            assert!((ev - exact).abs() < 1e-9, "{} != {}", ev, exact);
+        }
+    }
+
+    #[test]
+    fn test_ev_with_control_progress() {
+        let deck = Deck::new_from_strs(&vec!["A", "2", "3", "4", "5", "6", "7", "8", "9", "10"]);
+        let game = PreBlackJackGame::new(Rule::evolution_classic(), deck);
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sender = std::sync::Mutex::new(sender);
+        let control = EvControl::new().with_progress(move |progress| {
+            sender.lock().unwrap().send(progress).unwrap();
+        });
+        let ev: Ratio<BigInt> = game.ev_with_control(&control).unwrap();
+        assert_eq!(ev, game.ev());
+        drop(control);
+
+        let reports: Vec<Progress> = receiver.iter().collect();
+        let total = PreRoundPattern::all().len();
+        assert_eq!(reports.len(), total);
+        let last = reports.iter().max_by_key(|p| p.completed).unwrap();
+        assert_eq!(last.completed, total);
+        assert_eq!(last.estimated_remaining, Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_ev_with_deadline() {
+        // 他のテストが温めたグローバルのキャッシュで先に終わらないように、専用の Solver で計算する
+        let solver = Solver::new();
+        let game = PreBlackJackGame::new(Rule::evolution_classic(), Deck::new(8));
+        let control = EvControl::new().with_timeout(Duration::from_millis(500));
+
+        let start = Instant::now();
+        let ev = solver.pre_round_ev::<f64>(&game, &control);
+        assert_eq!(ev, Err(Error::DeadlineExceeded));
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_pattern_ev_range_split_aces() {
+        // 10 ばかりのシュー. スプリットした A は必ず A+10 になるが、BJではなく 21 なので 2 を超えない
+        let mut deck = Deck::zero();
+        for _ in 0..40 {
+            deck.add_mut(Card::Face);
+        }
+        let rule = Rule::evolution_classic();
+        let pre_round = PreBlackJackGame::new(rule.clone(), deck.clone());
+        let pattern = PreRoundPattern::all()
+            .iter()
+            .find(|pattern| {
+                pattern.player_hand == Hand::Pair(Card::Ace) && pattern.dealer_card == Card::N6
+            })
+            .unwrap();
+        let (lower, upper) = pre_round.pattern_ev_range::<f64>(pattern);
+
+        let game = BlackJackGame::new(rule, Card::N6, Hand::Pair(Card::Ace), deck, 2);
+        let action: ActionEV<f64> = game
+            .action_ev_in(&Solver::new(), &EvControl::new())
+            .unwrap();
+        assert_eq!(action.split, Some(2.0));
+        for (_, ev) in action.ranked() {
+            assert!(
+                lower <= ev && ev <= upper,
+                "{} not in {}..={}",
+                ev,
+                lower,
+                upper
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_ev() {
+        let deck = Deck::new_from_strs(&vec!["A", "2", "3", "4", "5", "6", "7", "8", "9", "10"]);
+        let game = PreBlackJackGame::new(Rule::evolution_classic(), deck);
+
+        let complete: PartialEv = game.partial_ev_with_control(&EvControl::new());
+        assert!(complete.is_complete());
+        assert_eq!(complete.completed, complete.total);
+        assert_eq!(
+            complete.evaluated_mass,
+            Ratio::from_integer(BigInt::from(1))
+        );
+        assert_eq!(complete.lower, complete.ev);
+        assert_eq!(complete.upper, complete.ev);
+
+        // 半分ほど終わったところでキャンセル
+        let token = CancellationToken::new();
+        let control = EvControl::from(&token).with_progress({
+            let token = token.clone();
+            move |progress| {
+                if progress.completed * 2 >= progress.total {
+                    token.cancel();
+                }
+            }
+        });
+        let partial: PartialEv = game.partial_ev_with_control(&control);
+        assert_eq!(partial.interrupted, Some(Error::Cancelled));
+        assert!(partial.completed < partial.total);
+        assert!(partial.evaluated_mass < Ratio::from_integer(BigInt::from(1)));
+        assert!(partial.lower <= complete.ev && complete.ev <= partial.upper);
+        assert_eq!(partial.into_result(), Err(Error::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn test_ev_async_cancel_on_drop() {
+        let game = PreBlackJackGame::new(Rule::evolution_classic(), Deck::new(8));
+        let token = CancellationToken::new();
+        let control = EvControl::from(&token);
+
+        // partial_ev_async と同じ流れで、ワーカーが止まった理由をチャンネルで受け取る
+        // キャッシュが温まっていて先に終わらないように、専用の Solver で計算する
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let worker = background::run(&control, move |control| {
+            let partial = game.partial_ev_in::<f64>(&Solver::new(), control);
+            sender.send(partial.interrupted.clone()).unwrap();
+            partial
+        });
+
+        // 時間切れで future を drop すると、子トークンがキャンセルされて計算が止まる
+        let timeout = tokio::time::timeout(Duration::from_millis(200), worker).await;
+        assert!(timeout.is_err());
+        assert!(!token.is_cancelled());
+        let interrupted = receiver.recv_timeout(Duration::from_secs(60)).unwrap();
+        assert_eq!(interrupted, Some(Error::Cancelled));
+
+        // 止まっていれば、次の計算は前の計算を待たずに始まって終わる
+        let deck = Deck::new_from_strs(&vec!["A", "2", "3", "4", "5", "6", "7", "8", "9", "10"]);
+        let small = PreBlackJackGame::new(Rule::evolution_classic(), deck);
+        let start = Instant::now();
+        let ev = small.ev_async().await.unwrap();
+        assert_eq!(ev, small.ev());
+        assert!(start.elapsed() < Duration::from_secs(60));
+    }
+
     #[test]
     fn test_preround_dealer_prob() {
         let deck = Deck::new(8);
         let rule = Rule::evolution_classic();
         let pre_round = PreBlackJackGame::new(rule, deck.clone());
-        let probs = pre_round.dealer_prob();
+        let probs = pre_round.dealer_prob().unwrap();
         // probsをすべて足すと１になること
         assert_eq!(probs.iter().sum::<f64>(), 1.0);
     }
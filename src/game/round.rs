@@ -1,4 +1,7 @@
-use crate::models::{Card, Cards, Deck, Hand, Rule};
+use crate::cache::CacheBudget;
+use crate::game::{background, EvControl, EvNumber, Phase, Solver, SolverStats};
+use crate::models::{Card, Cards, DealerTotalProb, Deck, Hand, HandTotal, Rule};
+use crate::Error;
 use num_rational::Ratio;
 use serde::{Deserialize, Serialize};
 
@@ -20,21 +23,28 @@ pub struct BlackJackGame {
     pub player_card_count: usize,
 }
 
-use std::{
-    collections::BTreeMap,
-    sync::{LazyLock, RwLock},
-};
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Action {
+    Stand,
+    Hit,
+    Double,
+    Split,
+}
+
+impl Action {
+    pub const ALL: [Action; 4] = [Action::Stand, Action::Hit, Action::Double, Action::Split];
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ActionEV {
-    pub stand: Ratio<BigInt>,
-    pub hit: Option<Ratio<BigInt>>,
-    pub double: Option<Ratio<BigInt>>,
-    pub split: Option<Ratio<BigInt>>,
+pub struct ActionEV<N = Ratio<BigInt>> {
+    pub stand: N,
+    pub hit: Option<N>,
+    pub double: Option<N>,
+    pub split: Option<N>,
 }
 
-impl ActionEV {
-    pub fn new(stand: Ratio<BigInt>) -> Self {
+impl<N: EvNumber> ActionEV<N> {
+    pub fn new(stand: N) -> Self {
         ActionEV {
             stand,
             hit: None,
@@ -43,7 +53,36 @@ impl ActionEV {
         }
     }
 
-    fn max_ev(&self) -> Ratio<BigInt> {
+    pub fn get(&self, action: Action) -> Option<&N> {
+        match action {
+            Action::Stand => Some(&self.stand),
+            Action::Hit => self.hit.as_ref(),
+            Action::Double => self.double.as_ref(),
+            Action::Split => self.split.as_ref(),
+        }
+    }
+
+    // 選べる行動をEVの高い順に. 同じEVなら Action::ALL の順
+    pub fn ranked(&self) -> Vec<(Action, N)> {
+        let mut actions: Vec<(Action, N)> = Action::ALL
+            .iter()
+            .filter_map(|&action| self.get(action).map(|ev| (action, ev.clone())))
+            .collect();
+        actions.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        actions
+    }
+
+    pub fn best_action(&self) -> Action {
+        self.ranked()[0].0
+    }
+
+    // action を選んだときに、一番良い行動と比べて失うEV. 選べない行動なら None
+    pub fn regret(&self, action: Action) -> Option<N> {
+        let ev = self.get(action)?;
+        Some(self.max_ev() - ev.clone())
+    }
+
+    fn max_ev(&self) -> N {
         let mut max_ev = self.stand.clone();
 
         for ev in [self.hit.clone(), self.double.clone(), self.split.clone()]
@@ -59,12 +98,46 @@ impl ActionEV {
     }
 }
 
-pub static BLACK_JACK_GAME_STAND_REPOSITORY: LazyLock<
-    RwLock<BTreeMap<BlackJackGame, Ratio<BigInt>>>,
-> = LazyLock::new(|| RwLock::new(BTreeMap::new()));
-pub static BLACK_JACK_GAME_HIT_REPOSITORY: LazyLock<
-    RwLock<BTreeMap<BlackJackGame, Ratio<BigInt>>>,
-> = LazyLock::new(|| RwLock::new(BTreeMap::new()));
+// 引いたカードの確率
+pub(crate) fn draw_prob<N: EvNumber>(deck: &Deck, rank: Card) -> N {
+    let draw_prob = deck.draw_probability(rank);
+    N::from_ratio(*draw_prob.numer() as u128, *draw_prob.denom() as u128)
+}
+
+// peekありでディーラーがBJでないと分かった後に、プレイヤーが rank を引く確率
+// ホールカードはBJにならないカードなので、素の draw_prob とは違う
+// P(rank) * P(BJでない | 引いた後のデッキ) / P(BJでない | 今のデッキ) になる
+// 場面ごとのEVはBJでない条件付きなので、この重みで足すとホールカードを列挙したのと同じになる
+pub(crate) fn player_draw_prob<N: EvNumber>(game: &BlackJackGame, rank: Card) -> N {
+    let blackjack_card = match game.dealer_card {
+        Card::Ace => Card::Face,
+        Card::Face => Card::Ace,
+        _ => return draw_prob(&game.deck_cards, rank),
+    };
+    let total = game.deck_cards.total_cards();
+    let no_blackjack = total - game.deck_cards.count(blackjack_card);
+    if !game.rule.dealer_peek || no_blackjack == 0 || total <= 1 {
+        return draw_prob(&game.deck_cards, rank);
+    }
+
+    let next_no_blackjack = if rank == blackjack_card {
+        no_blackjack
+    } else {
+        no_blackjack - 1
+    };
+    N::from_ratio(
+        (game.deck_cards.count(rank) * next_no_blackjack) as u128,
+        ((total - 1) * no_blackjack) as u128,
+    )
+}
+
+// スプリットした後の2枚のハンド. A と 10 でもBJではなく、ただの 21 として払う
+pub(crate) fn split_hand(card: Card, rank: Card) -> Hand {
+    match Cards::from_smallvec(smallvec![card, rank]).into() {
+        Hand::Soft(HandTotal::BlackJack) => Hand::Soft(HandTotal::Value(21)),
+        hand => hand,
+    }
+}
 
 impl BlackJackGame {
     pub fn new(
@@ -83,63 +156,130 @@ impl BlackJackGame {
         }
     }
 
-    pub fn clear_cache() {
-        if let Ok(mut map) = BLACK_JACK_GAME_STAND_REPOSITORY.write() {
-            map.clear();
-        }
+    // 外部入力から作るとき用. 手札・アップカード・残りデッキを合わせて
+    // rule.decks 組のシューに収まらなければ、計算を始める前にエラーにする
+    pub fn try_new(
+        rule: Rule,
+        dealer_card: Card,
+        player_cards: &Cards,
+        deck_cards: Deck,
+    ) -> Result<Self, Error> {
+        rule.validate()?;
 
-        if let Ok(mut map) = BLACK_JACK_GAME_HIT_REPOSITORY.write() {
-            map.clear();
+        let player_hand: Hand = player_cards.clone().into();
+        if player_hand == Hand::None {
+            return Err(Error::InvalidHand("player hand is empty".to_string()));
+        }
+        if player_hand.is_burst() {
+            return Err(Error::InvalidHand(format!(
+                "player hand is already bust: {:?}",
+                player_cards.cards
+            )));
+        }
+        if deck_cards.total_cards() == 0 {
+            return Err(Error::InvalidDeck("deck is empty".to_string()));
         }
 
-        Deck::clear_cache();
+        let mut used = Deck::new_from_cards(player_cards);
+        used.try_add_mut(dealer_card)?;
+        Deck::new(rule.decks)
+            .try_remove_deck(&used)
+            .and_then(|rest| rest.try_remove_deck(&deck_cards))
+            .map_err(|_| {
+                Error::InvalidDeck(format!(
+                    "player cards {:?}, dealer card {:?} and the remaining deck do not fit in {} decks",
+                    player_cards.cards, dealer_card, rule.decks
+                ))
+            })?;
+
+        Ok(BlackJackGame {
+            rule: Arc::new(rule),
+            dealer_card,
+            player_hand,
+            deck_cards,
+            player_card_count: player_cards.cards.len(),
+        })
     }
 
-    pub fn insert_stand_ev(&self, ev: &Ratio<BigInt>) {
-        let game_clone = self.clone();
-        let ev_clone = ev.clone();
+    // デフォルトの Solver のキャッシュを消す. 自分で作った Solver には影響しない
+    pub fn clear_cache() {
+        Solver::global().clear_cache();
+    }
 
-        if let Ok(mut map) = BLACK_JACK_GAME_STAND_REPOSITORY.write() {
-            map.insert(game_clone, ev_clone);
-        } else {
-            eprintln!("Failed to acquire write lock for BLACK_JACK_GAME_STAND_REPOSITORY");
-        }
+    // デフォルトの Solver のメモリ予算を設定する
+    pub fn set_cache_budget(budget: CacheBudget) {
+        Solver::global().set_cache_budget(budget);
     }
 
-    pub fn insert_hit_ev(&self, ev: &Ratio<BigInt>) {
-        let game_clone = self.clone();
-        let ev_clone = ev.clone();
+    // デフォルトの Solver のキャッシュと計算時間の統計
+    pub fn stats() -> SolverStats {
+        Solver::global().stats()
+    }
 
-        if let Ok(mut map) = BLACK_JACK_GAME_HIT_REPOSITORY.write() {
-            map.insert(game_clone, ev_clone);
-        } else {
-            eprintln!("Failed to acquire write lock for BLACK_JACK_GAME_HIT_REPOSITORY");
-        }
+    pub fn reset_stats() {
+        Solver::global().reset_stats();
+    }
+
+    pub fn insert_stand_ev<N: EvNumber>(&self, ev: &N) {
+        N::stand_cache(Solver::global()).insert(self.cache_key(), ev.clone());
+    }
+
+    pub fn insert_hit_ev<N: EvNumber>(&self, ev: &N) {
+        N::hit_cache(Solver::global()).insert(self.cache_key(), ev.clone());
     }
 
     pub fn get_stand_ev(
         &self,
         cancellation_token: &CancellationToken,
-    ) -> Result<Ratio<BigInt>, ()> {
+    ) -> Result<Ratio<BigInt>, Error> {
+        self.get_stand_ev_as(cancellation_token)
+    }
+
+    pub fn get_stand_ev_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<N, Error> {
+        self.get_stand_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
+
+    pub(crate) fn get_stand_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<N, Error> {
         if self.player_hand.is_lteq_11() || self.player_hand.is_burst() {
-            return Ok(Ratio::from_integer(BigInt::from(-1)));
+            return Ok(N::from_integer(-1));
         }
 
-        if let Ok(map) = BLACK_JACK_GAME_STAND_REPOSITORY.read() {
-            if let Some(ev) = map.get(self) {
-                return Ok(ev.clone());
-            }
+        let key = self.cache_key();
+        if let Some(ev) = N::stand_cache(solver).get(&key) {
+            return Ok(ev);
         }
 
-        let ev = self.stand_ev(cancellation_token)?;
-        self.insert_stand_ev(&ev);
+        let ev = self.stand_ev_in::<N>(solver, control)?;
+        N::stand_cache(solver).insert(key, ev.clone());
         Ok(ev)
     }
 
     pub fn get_hit_ev(
         &self,
         cancellation_token: &CancellationToken,
-    ) -> Result<Option<Ratio<BigInt>>, ()> {
+    ) -> Result<Option<Ratio<BigInt>>, Error> {
+        self.get_hit_ev_as(cancellation_token)
+    }
+
+    pub fn get_hit_ev_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Option<N>, Error> {
+        self.get_hit_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
+
+    pub(crate) fn get_hit_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<Option<N>, Error> {
         if self.player_hand.is_21()
             || self.player_hand.is_blackjack()
             || self.player_hand.is_burst()
@@ -147,28 +287,40 @@ impl BlackJackGame {
             return Ok(None);
         }
 
-        if let Ok(map) = BLACK_JACK_GAME_HIT_REPOSITORY.read() {
-            if let Some(ev) = map.get(self) {
-                return Ok(Some(ev.clone()));
-            }
+        let key = self.cache_key();
+        if let Some(ev) = N::hit_cache(solver).get(&key) {
+            return Ok(Some(ev));
         }
 
-        let ev = self.hit_ev(cancellation_token)?;
-        self.insert_hit_ev(&ev);
+        let ev = self.hit_ev_in::<N>(solver, control)?;
+        N::hit_cache(solver).insert(key, ev.clone());
         Ok(Some(ev))
     }
 
-    pub fn action_ev(&self, cancellation_token: &CancellationToken) -> Result<ActionEV, ()> {
-        if cancellation_token.is_cancelled() {
-            return Err(()); // キャンセルされている場合は即座にエラーを返す
-        }
+    pub fn action_ev(&self, cancellation_token: &CancellationToken) -> Result<ActionEV, Error> {
+        self.action_ev_as(cancellation_token)
+    }
+
+    pub fn action_ev_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<ActionEV<N>, Error> {
+        self.action_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
+
+    pub(crate) fn action_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<ActionEV<N>, Error> {
+        control.check()?;
 
         // stand, hit, double, splitのうち、1つでもErrが返ってきた場合は、その時点でErrを返す
         // それ以外の場合は、ActionEVを返す
-        let stand = self.get_stand_ev(cancellation_token)?;
-        let hit = self.get_hit_ev(cancellation_token)?;
-        let double = self.double_ev(cancellation_token)?;
-        let split = self.split_ev(cancellation_token)?;
+        let stand = self.get_stand_ev_in(solver, control)?;
+        let hit = self.get_hit_ev_in(solver, control)?;
+        let double = self.double_ev_in(solver, control)?;
+        let split = self.split_ev_in(solver, control)?;
 
         Ok(ActionEV {
             stand,
@@ -181,10 +333,25 @@ impl BlackJackGame {
     pub fn hit_or_stand_ev(
         &self,
         cancellation_token: &CancellationToken,
-    ) -> Result<Ratio<BigInt>, ()> {
-        let stand_ev = self.get_stand_ev(cancellation_token)?;
+    ) -> Result<Ratio<BigInt>, Error> {
+        self.hit_or_stand_ev_as(cancellation_token)
+    }
 
-        if let Some(hit_ev) = self.get_hit_ev(cancellation_token)? {
+    pub fn hit_or_stand_ev_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<N, Error> {
+        self.hit_or_stand_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
+
+    pub(crate) fn hit_or_stand_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        let stand_ev = self.get_stand_ev_in::<N>(solver, control)?;
+
+        if let Some(hit_ev) = self.get_hit_ev_in::<N>(solver, control)? {
             if hit_ev > stand_ev {
                 return Ok(hit_ev);
             }
@@ -193,119 +360,162 @@ impl BlackJackGame {
     }
 
     pub fn ev(&self) -> Ratio<BigInt> {
+        self.ev_as()
+    }
+
+    pub fn ev_as<N: EvNumber>(&self) -> N {
         let cancellation_token = CancellationToken::new();
-        self.ev_with_cancellation_token(&cancellation_token)
+        self.ev_with_cancellation_token_as(&cancellation_token)
             .unwrap()
     }
 
     pub fn ev_with_cancellation_token(
         &self,
         cancellation_token: &CancellationToken,
-    ) -> Result<Ratio<BigInt>, ()> {
-        let action_ev = self.action_ev(cancellation_token)?;
-        Ok(action_ev.max_ev())
+    ) -> Result<Ratio<BigInt>, Error> {
+        self.ev_with_cancellation_token_as(cancellation_token)
     }
 
-    pub fn stand_ev(&self, cancellation_token: &CancellationToken) -> Result<Ratio<BigInt>, ()> {
-        if self.player_hand.is_burst() {
-            return Ok(Ratio::from_integer(BigInt::from(-1)));
-        }
+    // 期限を指定して計算する. 期限を過ぎたら Error::DeadlineExceeded
+    pub fn ev_with_control<N: EvNumber>(&self, control: &EvControl) -> Result<N, Error> {
+        self.ev_in(Solver::global(), control)
+    }
 
-        if self.rule.six_card_charlie && self.player_card_count >= 6 {
-            return Ok(Ratio::from_integer(BigInt::from(1)));
-        }
+    pub fn ev_with_cancellation_token_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<N, Error> {
+        self.ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
+
+    // 専用のスレッドプールで計算する. future を drop するとキャンセルされる
+    // デフォルトの Solver を使う. Solver やプールを選ぶときは Solver::ev_async など
+    pub async fn ev_async(&self) -> Result<Ratio<BigInt>, Error> {
+        self.ev_with_control_async(&EvControl::new()).await
+    }
+
+    pub async fn ev_with_control_async<N: EvNumber>(
+        &self,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        let game = self.clone();
+        background::run(control, move |control| {
+            game.ev_in(Solver::global(), control)
+        })
+        .await
+    }
 
-        let user_total_value = self.player_hand.hand_total();
+    pub async fn action_ev_async<N: EvNumber>(
+        &self,
+        control: &EvControl,
+    ) -> Result<ActionEV<N>, Error> {
+        let game = self.clone();
+        background::run(control, move |control| {
+            game.action_ev_in(Solver::global(), control)
+        })
+        .await
+    }
 
-        let mut black_jack_prob = Ratio::from_integer(BigInt::from(0));
-        let mut win_prob = Ratio::from_integer(BigInt::from(0));
-        let mut lose_prob = Ratio::from_integer(BigInt::from(0));
+    pub(crate) fn ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        let action_ev = self.action_ev_in::<N>(solver, control)?;
+        Ok(action_ev.max_ev())
+    }
 
-        let binding = self.deck_cards.dealer_probs();
+    pub fn stand_ev(&self, cancellation_token: &CancellationToken) -> Result<Ratio<BigInt>, Error> {
+        self.stand_ev_as(cancellation_token)
+    }
 
-        let probs = binding.get(self.dealer_card);
+    pub fn stand_ev_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<N, Error> {
+        self.stand_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
 
-        for (dealer_total_value, ratio) in probs.iter() {
-            if cancellation_token.is_cancelled() {
-                return Err(());
+    pub(crate) fn stand_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        solver.time(Phase::Stand, || {
+            if self.player_hand.is_burst() {
+                return Ok(N::from_integer(-1));
             }
 
-            let bigint_ratio =
-                Ratio::new(BigInt::from(*ratio.numer()), BigInt::from(*ratio.denom()));
-            if self.player_hand.is_blackjack() {
-                if !dealer_total_value.is_blackjack() {
-                    black_jack_prob += bigint_ratio
-                }
-                // playerがブラックジャックでかつ、dealerがブラックジャックの場合は、pushのため、何もしない
-            } else if dealer_total_value.is_blackjack() {
-                lose_prob += bigint_ratio;
-            } else if dealer_total_value.is_burst() {
-                win_prob += bigint_ratio;
-            } else if dealer_total_value < &user_total_value {
-                win_prob += bigint_ratio;
-            } else if dealer_total_value > &user_total_value {
-                lose_prob += bigint_ratio;
+            if self.rule.six_card_charlie && self.player_card_count >= 6 {
+                return Ok(N::from_integer(1));
             }
-        }
 
-        Ok(Ratio::from_float(1.5).unwrap() * black_jack_prob + win_prob - lose_prob)
-    }
+            let user_total_value = self.player_hand.hand_total();
 
-    pub fn hit_ev(&self, cancellation_token: &CancellationToken) -> Result<Ratio<BigInt>, ()> {
-        if cancellation_token.is_cancelled() {
-            return Err(());
-        }
+            let mut black_jack_prob = N::from_integer(0);
+            let mut win_prob = N::from_integer(0);
+            let mut lose_prob = N::from_integer(0);
 
-        self.deck_cards
-            .remaining_ranks()
-            .par_iter() // 並列イテレーションに変更
-            .map(|&rank| {
-                if cancellation_token.is_cancelled() {
-                    return Err(());
-                }
+            let probs = self.dealer_total_prob(solver)?;
 
-                let next_deck_cards = self.deck_cards.remove(rank);
+            for (dealer_total_value, ratio) in probs.iter() {
+                control.check()?;
 
-                let round = BlackJackGame {
-                    rule: Arc::clone(&self.rule),
-                    dealer_card: self.dealer_card,
-                    player_hand: self.player_hand.add(rank),
-                    deck_cards: next_deck_cards,
-                    player_card_count: self.player_card_count + 1,
-                };
+                let prob = N::from_ratio(*ratio.numer(), *ratio.denom());
+                if self.player_hand.is_blackjack() {
+                    if !dealer_total_value.is_blackjack() {
+                        black_jack_prob = black_jack_prob + prob;
+                    }
+                    // playerがブラックジャックでかつ、dealerがブラックジャックの場合は、pushのため、何もしない
+                } else if dealer_total_value.is_blackjack() {
+                    lose_prob = lose_prob + prob;
+                } else if dealer_total_value.is_burst() || dealer_total_value < &user_total_value {
+                    win_prob = win_prob + prob;
+                } else if dealer_total_value > &user_total_value {
+                    lose_prob = lose_prob + prob;
+                }
+            }
 
-                let draw_prob = self.deck_cards.draw_probability(rank);
-                let hit_or_stand_ev = round.hit_or_stand_ev(cancellation_token)?;
+            Ok(N::from_ratio(3, 2) * black_jack_prob + win_prob - lose_prob)
+        })
+    }
 
-                Ok(hit_or_stand_ev
-                    * Ratio::new(
-                        BigInt::from(*draw_prob.numer()),
-                        BigInt::from(*draw_prob.denom()),
-                    ))
-            })
-            .collect::<Result<Vec<Ratio<BigInt>>, ()>>()
-            .map(|evs| evs.iter().sum())
+    // peekありのときは、ディーラーがBJでない条件付きの分布を使う
+    // BJだった場合の損益は PreBlackJackGame 側で足す
+    fn dealer_total_prob(&self, solver: &Solver) -> Result<DealerTotalProb, Error> {
+        let probs = solver.dealer_probs(&self.deck_cards)?;
+        let dealer_total_prob = probs.get(self.dealer_card);
+        if self.rule.dealer_peek {
+            dealer_total_prob.given_no_blackjack()
+        } else {
+            Ok(dealer_total_prob.clone())
+        }
     }
 
-    pub fn double_ev(
+    pub fn hit_ev(&self, cancellation_token: &CancellationToken) -> Result<Ratio<BigInt>, Error> {
+        self.hit_ev_as(cancellation_token)
+    }
+
+    pub fn hit_ev_as<N: EvNumber>(
         &self,
         cancellation_token: &CancellationToken,
-    ) -> Result<Option<Ratio<BigInt>>, ()> {
-        if self.player_hand.is_21()
-            || self.player_hand.is_blackjack()
-            || self.player_hand.is_burst()
-        {
-            return Ok(None);
-        }
+    ) -> Result<N, Error> {
+        self.hit_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
+
+    pub(crate) fn hit_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        solver.time(Phase::Hit, || {
+            control.check()?;
 
-        Ok(Some(
             self.deck_cards
                 .remaining_ranks()
-                .par_iter()
+                .par_iter() // 並列イテレーションに変更
                 .map(|&rank| {
-                    if cancellation_token.is_cancelled() {
-                        return Err(());
-                    }
+                    control.check()?;
 
                     let next_deck_cards = self.deck_cards.remove(rank);
 
@@ -316,85 +526,218 @@ impl BlackJackGame {
                         deck_cards: next_deck_cards,
                         player_card_count: self.player_card_count + 1,
                     };
-                    let draw_prob = self.deck_cards.draw_probability(rank);
 
-                    // 合計が9、10、11のツーカードハンドでのフリーダブル
-                    let ev = round.stand_ev(cancellation_token)?
-                        * Ratio::new(
-                            BigInt::from(*draw_prob.numer()),
-                            BigInt::from(*draw_prob.denom()),
-                        )
-                        * BigInt::from(2);
+                    let hit_or_stand_ev = round.hit_or_stand_ev_in::<N>(solver, control)?;
 
-                    Ok(ev)
+                    Ok(hit_or_stand_ev * player_draw_prob(self, rank))
                 })
-                .collect::<Result<Vec<Ratio<BigInt>>, ()>>()?
-                .iter()
-                .sum::<Ratio<BigInt>>(),
-        ))
+                .collect::<Result<Vec<N>, Error>>()
+                .map(|evs| evs.into_iter().sum())
+        })
     }
 
-    pub fn split_ev(
+    pub fn double_ev(
         &self,
         cancellation_token: &CancellationToken,
-    ) -> Result<Option<Ratio<BigInt>>, ()> {
-        let player_card: Card;
-        match self.player_hand {
-            Hand::Pair(c) => {
-                player_card = c;
+    ) -> Result<Option<Ratio<BigInt>>, Error> {
+        self.double_ev_as(cancellation_token)
+    }
+
+    pub fn double_ev_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Option<N>, Error> {
+        self.double_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
+
+    pub(crate) fn double_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<Option<N>, Error> {
+        solver.time(Phase::Double, || {
+            if self.player_hand.is_21()
+                || self.player_hand.is_blackjack()
+                || self.player_hand.is_burst()
+            {
+                return Ok(None);
             }
-            _ => return Ok(None),
-        }
 
-        Ok(Some(
-            self.deck_cards
-                .remaining_ranks()
-                .par_iter() // 並列イテレーションに変更
-                .map(|&rank| {
-                    if cancellation_token.is_cancelled() {
-                        return Err(());
-                    }
+            Ok(Some(
+                self.deck_cards
+                    .remaining_ranks()
+                    .par_iter()
+                    .map(|&rank| {
+                        control.check()?;
+
+                        let next_deck_cards = self.deck_cards.remove(rank);
+
+                        let round = BlackJackGame {
+                            rule: Arc::clone(&self.rule),
+                            dealer_card: self.dealer_card,
+                            player_hand: self.player_hand.add(rank),
+                            deck_cards: next_deck_cards,
+                            player_card_count: self.player_card_count + 1,
+                        };
+
+                        // 合計が9、10、11のツーカードハンドでのフリーダブル
+                        let ev = round.stand_ev_in::<N>(solver, control)?
+                            * player_draw_prob::<N>(self, rank)
+                            * N::from_integer(2);
+
+                        Ok(ev)
+                    })
+                    .collect::<Result<Vec<N>, Error>>()?
+                    .into_iter()
+                    .sum::<N>(),
+            ))
+        })
+    }
 
-                    let next_deck_cards = self.deck_cards.remove(rank);
+    pub fn split_ev(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Option<Ratio<BigInt>>, Error> {
+        self.split_ev_as(cancellation_token)
+    }
 
-                    let round = BlackJackGame {
-                        rule: Arc::clone(&self.rule),
-                        dealer_card: self.dealer_card,
-                        player_hand: Cards::from_smallvec(smallvec![player_card, rank]).into(),
-                        deck_cards: next_deck_cards,
-                        player_card_count: 1,
-                    };
+    pub fn split_ev_as<N: EvNumber>(
+        &self,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Option<N>, Error> {
+        self.split_ev_in(Solver::global(), &EvControl::from(cancellation_token))
+    }
 
-                    let draw_prob = self.deck_cards.draw_probability(rank);
-                    if player_card == Card::Ace {
-                        Ok(round.get_stand_ev(cancellation_token)?
-                            * BigInt::from(2)
-                            * Ratio::new(
-                                BigInt::from(*draw_prob.numer()),
-                                BigInt::from(*draw_prob.denom()),
-                            ))
-                    } else {
-                        Ok(round.hit_or_stand_ev(cancellation_token)?
-                            * BigInt::from(2)
-                            * Ratio::new(
-                                BigInt::from(*draw_prob.numer()),
-                                BigInt::from(*draw_prob.denom()),
-                            ))
-                    }
-                })
-                .collect::<Result<Vec<Ratio<BigInt>>, ()>>()?
-                .iter()
-                .sum::<Ratio<BigInt>>(),
-        ))
+    pub(crate) fn split_ev_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<Option<N>, Error> {
+        solver.time(Phase::Split, || {
+            let player_card = match self.player_hand {
+                Hand::Pair(c) => c,
+                _ => return Ok(None),
+            };
+
+            Ok(Some(
+                self.deck_cards
+                    .remaining_ranks()
+                    .par_iter() // 並列イテレーションに変更
+                    .map(|&rank| {
+                        control.check()?;
+
+                        let next_deck_cards = self.deck_cards.remove(rank);
+
+                        let round = BlackJackGame {
+                            rule: Arc::clone(&self.rule),
+                            dealer_card: self.dealer_card,
+                            player_hand: split_hand(player_card, rank),
+                            deck_cards: next_deck_cards,
+                            player_card_count: 1,
+                        };
+
+                        let ev = if player_card == Card::Ace {
+                            round.get_stand_ev_in::<N>(solver, control)?
+                        } else {
+                            round.hit_or_stand_ev_in::<N>(solver, control)?
+                        };
+                        Ok(ev * N::from_integer(2) * player_draw_prob(self, rank))
+                    })
+                    .collect::<Result<Vec<N>, Error>>()?
+                    .into_iter()
+                    .sum::<N>(),
+            ))
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::HandTotal;
+    use num::ToPrimitive;
     use smallvec::smallvec;
 
+    #[test]
+    fn test_action_ev_ranked() {
+        let action_ev = ActionEV {
+            stand: -0.5,
+            hit: Some(-0.2),
+            double: Some(-0.4),
+            split: None,
+        };
+        assert_eq!(action_ev.best_action(), Action::Hit);
+        assert_eq!(
+            action_ev.ranked(),
+            vec![
+                (Action::Hit, -0.2),
+                (Action::Double, -0.4),
+                (Action::Stand, -0.5)
+            ]
+        );
+        assert_eq!(action_ev.get(Action::Split), None);
+        assert_eq!(action_ev.regret(Action::Hit), Some(0.0));
+        assert_eq!(action_ev.regret(Action::Stand), Some(0.3));
+        assert_eq!(action_ev.regret(Action::Split), None);
+    }
+
+    #[tokio::test]
+    async fn test_ev_async() {
+        let game = BlackJackGame::new(
+            Rule::evolution_classic(),
+            Card::N6,
+            Hand::Hard(HandTotal::Value(12)),
+            Deck::new(1),
+            2,
+        );
+        assert_eq!(game.ev_async().await.unwrap(), game.ev());
+
+        let action: ActionEV<f64> = game.action_ev_async(&EvControl::new()).await.unwrap();
+        assert!(action.hit.is_some());
+    }
+
+    #[test]
+    fn test_try_new() {
+        let mut rule = Rule::evolution_classic();
+        rule.decks = 1;
+        let player_cards = Cards::new_from_strs(&vec!["10", "6"]);
+        let deck = Deck::new(1)
+            .remove(Card::Face)
+            .remove(Card::N6)
+            .remove(Card::N9);
+
+        let game =
+            BlackJackGame::try_new(rule.clone(), Card::N9, &player_cards, deck.clone()).unwrap();
+        assert_eq!(game.player_hand, Hand::Hard(HandTotal::Value(16)));
+        assert_eq!(game.player_card_count, 2);
+
+        // アップカードがデッキから抜かれていない
+        assert!(matches!(
+            BlackJackGame::try_new(rule.clone(), Card::N9, &player_cards, deck.add(Card::N9)),
+            Err(Error::InvalidDeck(_))
+        ));
+        // 1デッキに5枚目のAはない
+        let aces = Cards::new_from_strs(&vec!["A", "A", "A", "A"]);
+        assert!(matches!(
+            BlackJackGame::try_new(rule.clone(), Card::Ace, &aces, Deck::new(1)),
+            Err(Error::InvalidDeck(_))
+        ));
+        assert!(matches!(
+            BlackJackGame::try_new(rule.clone(), Card::N9, &Cards::new(), deck.clone()),
+            Err(Error::InvalidHand(_))
+        ));
+        let bust = Cards::new_from_strs(&vec!["10", "6", "8"]);
+        assert!(matches!(
+            BlackJackGame::try_new(rule.clone(), Card::N9, &bust, deck.clone()),
+            Err(Error::InvalidHand(_))
+        ));
+
+        rule.hit_split_aces = true;
+        assert!(matches!(
+            BlackJackGame::try_new(rule, Card::N9, &player_cards, deck),
+            Err(Error::UnsupportedRule(_))
+        ));
+    }
+
     #[test]
     fn test_stand_ev1() {
         let dealer_card = Card::Face;
@@ -507,6 +850,221 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stand_ev_dealer_peek() {
+        let dealer_card = Card::Face;
+        let user_cards = Cards::from_smallvec(smallvec![Card::Face, Card::Face]).into();
+        let deck_cards = Deck::new_from_strs(&vec!["A", "T"]);
+        let token = CancellationToken::new();
+
+        // peekなし: 1/2でBJに負け、1/2で20のpush
+        let rule = Rule::evolution_classic();
+        let round = BlackJackGame::new(rule, dealer_card, user_cards, deck_cards.clone(), 2);
+        assert_eq!(
+            round.stand_ev(&token).unwrap(),
+            Ratio::new(BigInt::from(-1), BigInt::from(2))
+        );
+
+        // peekあり: BJでないと分かっているので、20のpushだけ
+        let rule = Rule {
+            dealer_peek: true,
+            ..Rule::evolution_classic()
+        };
+        let round = BlackJackGame::new(rule, dealer_card, round.player_hand, deck_cards, 2);
+        assert_eq!(
+            round.stand_ev(&token).unwrap(),
+            Ratio::from_integer(BigInt::from(0))
+        );
+    }
+
+    // 総当たり用. (合計, ソフトか)
+    fn brute_total(cards: &[Card]) -> (usize, bool) {
+        let mut total: usize = cards.iter().map(|c| c.value()).sum();
+        let mut aces = cards.iter().filter(|&&c| c == Card::Ace).count();
+        while total > 21 && aces > 0 {
+            total -= 10;
+            aces -= 1;
+        }
+        (total, aces > 0)
+    }
+
+    // ディーラーの手札と残りのデッキから最後まで引いたときの、プレイヤーの total に対するEV
+    fn brute_dealer_ev(player_total: usize, dealer: &mut Vec<Card>, deck: &Deck) -> f64 {
+        let (total, _) = brute_total(dealer);
+        if total > 21 {
+            return 1.0;
+        }
+        if total >= 17 {
+            return match player_total.cmp(&total) {
+                std::cmp::Ordering::Greater => 1.0,
+                std::cmp::Ordering::Equal => 0.0,
+                std::cmp::Ordering::Less => -1.0,
+            };
+        }
+        let mut ev = 0.0;
+        for rank in deck.remaining_ranks() {
+            let prob = deck.count(rank) as f64 / deck.total_cards() as f64;
+            dealer.push(rank);
+            ev += prob * brute_dealer_ev(player_total, dealer, &deck.remove(rank));
+            dealer.pop();
+        }
+        ev
+    }
+
+    // ホールカードごとに「そのホールカードで、ここまでの引きになる」確率を持ち回す総当たり
+    // deck はホールカードを含む、プレイヤーから見えていないカード
+    struct Brute {
+        up: Card,
+        holes: Vec<(Card, f64)>,
+        deck: Deck,
+    }
+
+    impl Brute {
+        fn new(up: Card, blackjack_card: Card, deck: Deck) -> Self {
+            let holes = deck
+                .remaining_ranks()
+                .into_iter()
+                .filter(|&rank| rank != blackjack_card)
+                .map(|rank| (rank, deck.count(rank) as f64))
+                .collect();
+            Brute { up, holes, deck }
+        }
+
+        fn weight(&self) -> f64 {
+            self.holes.iter().map(|(_, w)| w).sum()
+        }
+
+        // rank を引いた後の情報と、それを引く確率
+        fn draw(&self, rank: Card) -> Option<(Brute, f64)> {
+            let holes: Vec<(Card, f64)> = self
+                .holes
+                .iter()
+                .map(|&(hole, w)| {
+                    let rest = self.deck.remove(hole);
+                    let prob = rest.count(rank) as f64 / rest.total_cards() as f64;
+                    (hole, w * prob)
+                })
+                .filter(|&(_, w)| w > 0.0)
+                .collect();
+            let next = Brute {
+                up: self.up,
+                holes,
+                deck: self.deck.remove(rank),
+            };
+            let prob = next.weight() / self.weight();
+            (prob > 0.0).then_some((next, prob))
+        }
+
+        fn draws(&self) -> Vec<(Brute, f64, Card)> {
+            self.deck
+                .remaining_ranks()
+                .into_iter()
+                .filter_map(|rank| self.draw(rank).map(|(next, prob)| (next, prob, rank)))
+                .collect()
+        }
+
+        fn stand(&self, player: &[Card]) -> f64 {
+            let (total, _) = brute_total(player);
+            if total > 21 {
+                return -1.0;
+            }
+            let ev: f64 = self
+                .holes
+                .iter()
+                .map(|&(hole, w)| {
+                    w * brute_dealer_ev(total, &mut vec![self.up, hole], &self.deck.remove(hole))
+                })
+                .sum();
+            ev / self.weight()
+        }
+
+        fn hit(&self, player: &[Card]) -> f64 {
+            self.draws()
+                .iter()
+                .map(|(next, prob, rank)| {
+                    let mut cards = player.to_vec();
+                    cards.push(*rank);
+                    prob * next.best(&cards)
+                })
+                .sum()
+        }
+
+        // BlackJackGame と同じく、11以下のスタンドは -1
+        fn best(&self, player: &[Card]) -> f64 {
+            let (total, _) = brute_total(player);
+            if total > 21 {
+                return -1.0;
+            }
+            let stand = if total <= 11 && player[0] != player[1] {
+                -1.0
+            } else {
+                self.stand(player)
+            };
+            if total == 21 {
+                return stand;
+            }
+            stand.max(self.hit(player))
+        }
+
+        fn double(&self, player: &[Card]) -> f64 {
+            self.draws()
+                .iter()
+                .map(|(next, prob, rank)| {
+                    let mut cards = player.to_vec();
+                    cards.push(*rank);
+                    2.0 * prob * next.stand(&cards)
+                })
+                .sum()
+        }
+
+        fn split(&self, card: Card) -> f64 {
+            self.draws()
+                .iter()
+                .map(|(next, prob, rank)| 2.0 * prob * next.best(&[card, *rank]))
+                .sum()
+        }
+    }
+
+    #[test]
+    fn test_dealer_peek_matches_brute_force() {
+        let rule = Rule {
+            dealer_peek: true,
+            ..Rule::evolution_classic()
+        };
+        let deck = Deck::new_from_strs(&vec![
+            "A", "A", "2", "3", "4", "5", "6", "7", "8", "9", "T", "T", "T", "T",
+        ]);
+        let control = EvControl::new();
+
+        for (up, blackjack_card, player) in [
+            (Card::Ace, Card::Face, vec![Card::N7, Card::N5]),
+            (Card::Face, Card::Ace, vec![Card::N8, Card::N8]),
+            (Card::Face, Card::Ace, vec![Card::N4, Card::N6]),
+        ] {
+            let game = BlackJackGame::new(
+                rule.clone(),
+                up,
+                Cards::from_smallvec(player.iter().copied().collect()).into(),
+                deck.clone(),
+                2,
+            );
+            let action: ActionEV<f64> = game.action_ev_in(&Solver::new(), &control).unwrap();
+            let brute = Brute::new(up, blackjack_card, deck.clone());
+
+            let stand = if brute_total(&player).0 <= 11 {
+                -1.0
+            } else {
+                brute.stand(&player)
+            };
+            assert!((action.stand - stand).abs() < 1e-12, "{:?}", player);
+            assert!((action.hit.unwrap() - brute.hit(&player)).abs() < 1e-12);
+            assert!((action.double.unwrap() - brute.double(&player)).abs() < 1e-12);
+            if player[0] == player[1] {
+                assert!((action.split.unwrap() - brute.split(player[0])).abs() < 1e-12);
+            }
+        }
+    }
+
     #[test]
     fn test_hit_ev1() {
         let dealer_card = Card::Face;
@@ -565,6 +1123,42 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_action_ev_f64() {
+        let dealer_card = Card::N6;
+        let token = CancellationToken::new();
+
+        for hand in [
+            Hand::Hard(HandTotal::Value(11)),
+            Hand::Hard(HandTotal::Value(16)),
+            Hand::Soft(HandTotal::Value(18)),
+            Hand::Pair(Card::N8),
+        ] {
+            let rule = Rule::evolution_classic();
+            let round = BlackJackGame::new(rule, dealer_card, hand.clone(), Deck::new(1), 2);
+
+            let exact = round.action_ev(&token).unwrap();
+            let approx = round.action_ev_as::<f64>(&token).unwrap();
+
+            let to_f64 = |ev: &Ratio<BigInt>| ev.to_f64().unwrap();
+            assert!(
+                (to_f64(&exact.stand) - approx.stand).abs() < 1e-12,
+                "hand: {:?}",
+                hand
+            );
+            for (exact, approx) in [
+                (exact.hit, approx.hit),
+                (exact.double, approx.double),
+                (exact.split, approx.split),
+            ] {
+                assert_eq!(exact.is_some(), approx.is_some(), "hand: {:?}", hand);
+                if let (Some(exact), Some(approx)) = (exact, approx) {
+                    assert!((to_f64(&exact) - approx).abs() < 1e-12, "hand: {:?}", hand);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_hit_ev_burst() {
         let dealer_card = Card::Face;
@@ -802,6 +1396,44 @@ mod tests {
         assert_eq!(round.ev(), Ratio::<BigInt>::new(numer, denom),);
     }
 
+    #[test]
+    fn test_ev_with_cache_budget() {
+        let dealer_card = Card::Face;
+        let user_cards = Hand::Hard(HandTotal::Value(12));
+        let deck_cards = Deck::new(1);
+        let rule = Rule::evolution_classic();
+
+        let round = BlackJackGame::new(rule, dealer_card, user_cards, deck_cards, 1);
+
+        // 捨てられても計算し直すだけなので、結果は変わらない
+        // デフォルトの Solver の予算は他のテストと共有なので、自分の Solver で試す
+        let solver = Solver::with_budget(CacheBudget::total(64 * 1024));
+        let ev: Ratio<BigInt> = solver.ev(&round, &EvControl::new()).unwrap();
+        let stats = solver.stats();
+        assert!(stats.stand_cache.evictions + stats.hit_cache.evictions > 0);
+
+        let numer: BigInt = "-9743496797".parse().unwrap();
+        let denom: BigInt = "22576144500".parse().unwrap();
+        assert_eq!(ev, Ratio::<BigInt>::new(numer, denom));
+    }
+
+    #[test]
+    fn test_cancelled_error() {
+        let rule = Rule::evolution_classic();
+        let round = BlackJackGame::new(
+            rule,
+            Card::Face,
+            Hand::Hard(HandTotal::Value(12)),
+            Deck::new(1),
+            1,
+        );
+        let token = CancellationToken::new();
+        token.cancel();
+
+        assert_eq!(round.action_ev(&token).unwrap_err(), Error::Cancelled);
+        assert_eq!(round.hit_ev(&token), Err(Error::Cancelled));
+    }
+
     #[test]
     fn test_hand_none_ev() {
         let dealer_card = Card::Face;
@@ -0,0 +1,544 @@
+use crate::cache::{BoundedCache, CacheBudget, DealerProbsCache};
+use crate::game::round::ActionEV;
+use crate::game::{
+    background, BlackJackGame, EvControl, EvNumber, GameKey, PartialEv, PreBlackJackGame,
+};
+use crate::models::{DealerHandPatterns, DealerHandProb, DealerProbEngine, Deck, Rule};
+use crate::strategy::{CompositionException, RegretReport, Strategy, StrategyChart};
+use crate::Error;
+
+use num::bigint::BigInt;
+use num::rational::Ratio;
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+use stats::PhaseTimer;
+use std::sync::{Arc, LazyLock};
+
+mod persist;
+mod stats;
+
+pub use persist::EV_CACHE_FORMAT_VERSION;
+pub use stats::{Phase, PhaseStats, SolverStats};
+
+// BlackJackGame / PreBlackJackGame のメソッドが使うデフォルトの Solver
+// ディーラーの分布のキャッシュは Deck::dealer_probs と共有する
+static DEFAULT_SOLVER: LazyLock<Solver> = LazyLock::new(|| Solver {
+    dealer_probs: Deck::default_dealer_probs_cache(),
+    ..Solver::new()
+});
+
+// メモ化のキャッシュを持つ計算のコンテキスト
+// テーブルごと、リクエストごとに作って、使い終わったら捨てられる
+// BlackJackGame / PreBlackJackGame のメソッドは Solver::global() とグローバルの rayon プール
+// (async 版は共有の専用プール) を使う. キャッシュやプールを分けたいときは Solver のメソッドで計算する
+pub struct Solver {
+    pub(crate) exact_stand_ev: BoundedCache<GameKey, Ratio<BigInt>>,
+    pub(crate) exact_hit_ev: BoundedCache<GameKey, Ratio<BigInt>>,
+    pub(crate) f64_stand_ev: BoundedCache<GameKey, f64>,
+    pub(crate) f64_hit_ev: BoundedCache<GameKey, f64>,
+    pub(crate) dealer_probs: Arc<DealerProbsCache>,
+    phase_timers: [PhaseTimer; Phase::ALL.len()],
+    // None ならグローバルの rayon プール
+    pool: Option<Arc<ThreadPool>>,
+    // None なら組み込みのテーブルを dealer_engine で計算する
+    dealer_patterns: Option<Arc<DealerHandPatterns>>,
+    dealer_engine: DealerProbEngine,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solver {
+    pub fn new() -> Self {
+        Solver {
+            exact_stand_ev: BoundedCache::new(),
+            exact_hit_ev: BoundedCache::new(),
+            f64_stand_ev: BoundedCache::new(),
+            f64_hit_ev: BoundedCache::new(),
+            dealer_probs: Arc::new(BoundedCache::new()),
+            phase_timers: Default::default(),
+            pool: None,
+            dealer_patterns: None,
+            dealer_engine: DealerProbEngine::default(),
+        }
+    }
+
+    // DealerHandPatterns::load で読み込んだ別のルールのテーブルでディーラーの分布を計算する
+    // 結果が変わるので、それまでのキャッシュは捨てて、Deck::dealer_probs とも共有しない
+    pub fn with_dealer_patterns(mut self, dealer_patterns: Arc<DealerHandPatterns>) -> Self {
+        self.use_own_dealer_cache();
+        self.dealer_patterns = Some(dealer_patterns);
+        self
+    }
+
+    pub fn dealer_patterns(&self) -> Option<&Arc<DealerHandPatterns>> {
+        self.dealer_patterns.as_ref()
+    }
+
+    // 組み込みのテーブルの分布を計算するエンジン. with_dealer_patterns のテーブルがあればそちらを使う
+    // 他のエンジンの値と混ざらないように、ディーラーの分布のキャッシュは Solver 専用にする
+    pub fn with_dealer_engine(mut self, dealer_engine: DealerProbEngine) -> Self {
+        self.use_own_dealer_cache();
+        self.dealer_engine = dealer_engine;
+        self
+    }
+
+    pub fn dealer_engine(&self) -> DealerProbEngine {
+        self.dealer_engine
+    }
+
+    fn use_own_dealer_cache(&mut self) {
+        let dealer_probs = BoundedCache::new();
+        dealer_probs.set_budget(self.dealer_probs.budget());
+        self.dealer_probs = Arc::new(dealer_probs);
+        self.clear_cache();
+    }
+
+    // 専用のプールで計算する. 他の rayon の処理とCPUを取り合わず、使うスレッド数も抑えられる
+    // 同じプールを複数の Solver で共有してもよい
+    pub fn with_thread_pool(mut self, pool: Arc<ThreadPool>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    pub fn with_threads(self, num_threads: usize) -> Result<Self, ThreadPoolBuildError> {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(|i| format!("bjc-solver-{}", i))
+            .build()?;
+        Ok(self.with_thread_pool(Arc::new(pool)))
+    }
+
+    pub fn thread_pool(&self) -> Option<&Arc<ThreadPool>> {
+        self.pool.as_ref()
+    }
+
+    pub fn current_num_threads(&self) -> usize {
+        match &self.pool {
+            Some(pool) => pool.current_num_threads(),
+            None => rayon::current_num_threads(),
+        }
+    }
+
+    // 中の par_iter は install したプールで動く. Solver のメソッドだけがここを通る
+    fn install<R, F>(&self, f: F) -> R
+    where
+        R: Send,
+        F: FnOnce() -> R + Send,
+    {
+        match &self.pool {
+            Some(pool) => pool.install(f),
+            None => f(),
+        }
+    }
+
+    pub fn with_budget(budget: CacheBudget) -> Self {
+        let solver = Self::new();
+        solver.set_cache_budget(budget);
+        solver
+    }
+
+    pub fn global() -> &'static Solver {
+        &DEFAULT_SOLVER
+    }
+
+    pub fn clear_cache(&self) {
+        self.exact_stand_ev.clear();
+        self.exact_hit_ev.clear();
+        self.f64_stand_ev.clear();
+        self.f64_hit_ev.clear();
+        self.dealer_probs.clear();
+    }
+
+    // stand_ev と hit_ev は厳密な値と f64 のキャッシュで半分ずつ分ける
+    // 両方の数値型で計算しても、合計が budget を超えない
+    pub fn set_cache_budget(&self, budget: CacheBudget) {
+        let half = |bytes: Option<usize>| bytes.map(|bytes| bytes / 2);
+        self.exact_stand_ev.set_budget(half(budget.stand_ev));
+        self.exact_hit_ev.set_budget(half(budget.hit_ev));
+        self.f64_stand_ev.set_budget(half(budget.stand_ev));
+        self.f64_hit_ev.set_budget(half(budget.hit_ev));
+        self.dealer_probs.set_budget(budget.dealer_probs);
+    }
+
+    pub fn dealer_probs(&self, deck: &Deck) -> Result<Arc<DealerHandProb>, Error> {
+        if let Some(dealer_probs) = self.dealer_probs.get(deck) {
+            return Ok(dealer_probs);
+        }
+
+        let dealer_probs = Arc::new(self.time(
+            Phase::DealerProbs,
+            || match &self.dealer_patterns {
+                Some(dealer_patterns) => deck.calc_dealer_hand_prob_with_patterns(dealer_patterns),
+                None => deck.calc_dealer_hand_prob_with(self.dealer_engine),
+            },
+        )?);
+        self.dealer_probs
+            .insert(deck.clone(), Arc::clone(&dealer_probs));
+
+        Ok(dealer_probs)
+    }
+
+    // 計算するメソッドは全て &EvControl を取る. トークンだけなら EvControl::from(&token)
+    pub fn action_ev<N: EvNumber>(
+        &self,
+        game: &BlackJackGame,
+        control: &EvControl,
+    ) -> Result<ActionEV<N>, Error> {
+        self.install(|| game.action_ev_in(self, control))
+    }
+
+    pub fn ev<N: EvNumber>(&self, game: &BlackJackGame, control: &EvControl) -> Result<N, Error> {
+        self.install(|| game.ev_in(self, control))
+    }
+
+    pub fn stand_ev<N: EvNumber>(
+        &self,
+        game: &BlackJackGame,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        self.install(|| game.stand_ev_in(self, control))
+    }
+
+    pub fn hit_ev<N: EvNumber>(
+        &self,
+        game: &BlackJackGame,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        self.install(|| game.hit_ev_in(self, control))
+    }
+
+    pub fn double_ev<N: EvNumber>(
+        &self,
+        game: &BlackJackGame,
+        control: &EvControl,
+    ) -> Result<Option<N>, Error> {
+        self.install(|| game.double_ev_in(self, control))
+    }
+
+    pub fn split_ev<N: EvNumber>(
+        &self,
+        game: &BlackJackGame,
+        control: &EvControl,
+    ) -> Result<Option<N>, Error> {
+        self.install(|| game.split_ev_in(self, control))
+    }
+
+    pub fn pre_round_ev<N: EvNumber>(
+        &self,
+        pre_round: &PreBlackJackGame,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        self.install(|| pre_round.calc_ev_in(self, control))
+    }
+
+    pub fn strategy_chart<N: EvNumber>(
+        &self,
+        rule: &Rule,
+        deck: &Deck,
+        control: &EvControl,
+    ) -> Result<StrategyChart<N>, Error> {
+        self.install(|| StrategyChart::generate_in(self, rule, deck, control))
+    }
+
+    pub fn composition_exceptions<N: EvNumber>(
+        &self,
+        chart: &StrategyChart<N>,
+        max_cards: usize,
+        control: &EvControl,
+    ) -> Result<Vec<CompositionException<N>>, Error> {
+        self.install(|| chart.composition_exceptions_in(self, max_cards, control))
+    }
+
+    pub fn regret_report<N: EvNumber, S: Strategy>(
+        &self,
+        pre_round: &PreBlackJackGame,
+        strategy: &S,
+        control: &EvControl,
+    ) -> Result<RegretReport<N>, Error> {
+        self.install(|| pre_round.regret_report_in(self, strategy, control))
+    }
+
+    // 途中で止まっても、終わったパターンの分は PartialEv にして返す
+    pub fn partial_ev<N: EvNumber>(
+        &self,
+        pre_round: &PreBlackJackGame,
+        control: &EvControl,
+    ) -> PartialEv<N> {
+        self.install(|| pre_round.partial_ev_in(self, control))
+    }
+
+    // async 版は with_thread_pool のプール (なければ async API 用の共有プール) で計算する
+    // 計算が終わるまで Solver を使うので Arc で持つ. future を drop するとキャンセルされる
+    pub async fn ev_async<N: EvNumber>(
+        self: &Arc<Self>,
+        game: &BlackJackGame,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        let solver = Arc::clone(self);
+        let game = game.clone();
+        self.spawn(control, move |control| game.ev_in(&solver, control))
+            .await
+    }
+
+    pub async fn action_ev_async<N: EvNumber>(
+        self: &Arc<Self>,
+        game: &BlackJackGame,
+        control: &EvControl,
+    ) -> Result<ActionEV<N>, Error> {
+        let solver = Arc::clone(self);
+        let game = game.clone();
+        self.spawn(control, move |control| game.action_ev_in(&solver, control))
+            .await
+    }
+
+    pub async fn pre_round_ev_async<N: EvNumber>(
+        self: &Arc<Self>,
+        pre_round: &PreBlackJackGame,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        let solver = Arc::clone(self);
+        let pre_round = pre_round.clone();
+        self.spawn(control, move |control| {
+            pre_round.calc_ev_in(&solver, control)
+        })
+        .await
+    }
+
+    pub async fn partial_ev_async<N: EvNumber>(
+        self: &Arc<Self>,
+        pre_round: &PreBlackJackGame,
+        control: &EvControl,
+    ) -> PartialEv<N> {
+        let solver = Arc::clone(self);
+        let pre_round = pre_round.clone();
+        self.spawn(control, move |control| {
+            pre_round.partial_ev_in(&solver, control)
+        })
+        .await
+    }
+
+    async fn spawn<T, F>(&self, control: &EvControl, f: F) -> T
+    where
+        T: Send + 'static,
+        F: FnOnce(&EvControl) -> T + Send + 'static,
+    {
+        match &self.pool {
+            Some(pool) => background::run_in(pool, control, f).await,
+            None => background::run(control, f).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::decimal;
+    use crate::models::{Card, Hand, HandTotal, Rule};
+    use tokio_util::sync::CancellationToken;
+
+    fn game(rule: Rule) -> BlackJackGame {
+        BlackJackGame::new(
+            rule,
+            Card::Face,
+            Hand::Hard(HandTotal::Value(12)),
+            Deck::new(1),
+            1,
+        )
+    }
+
+    #[test]
+    fn test_solver_owns_caches() {
+        let control = EvControl::new();
+        let solver = Solver::new();
+        // 計算の前から存在する別の Solver
+        let other = Solver::new();
+
+        let ev = solver
+            .ev::<Ratio<BigInt>>(&game(Rule::evolution_classic()), &control)
+            .unwrap();
+        assert_eq!(ev, game(Rule::evolution_classic()).ev());
+        assert!(!solver.exact_hit_ev.is_empty());
+        assert!(!solver.dealer_probs.is_empty());
+
+        // 別の Solver は影響を受けない
+        assert!(other.exact_stand_ev.is_empty());
+        assert!(other.exact_hit_ev.is_empty());
+        assert!(other.dealer_probs.is_empty());
+
+        solver.clear_cache();
+        assert!(solver.exact_hit_ev.is_empty());
+        assert!(solver.dealer_probs.is_empty());
+    }
+
+    #[test]
+    fn test_solver_cache_budget() {
+        let control = EvControl::new();
+        let budget = 8 * 1024;
+        let solver = Solver::with_budget(CacheBudget::total(budget));
+
+        // 厳密な値と f64 の両方で計算しても、キャッシュの合計は予算に収まる
+        let game = game(Rule::evolution_classic());
+        let exact = solver.ev::<Ratio<BigInt>>(&game, &control).unwrap();
+        let ev = solver.ev::<f64>(&game, &control).unwrap();
+        assert_eq!(exact, game.ev());
+        assert!((ev - decimal::to_f64(&exact)).abs() < 1e-9);
+
+        let stats = solver.stats();
+        assert!(stats.hit_cache.evictions > 0);
+        let bytes =
+            stats.stand_cache.bytes + stats.hit_cache.bytes + stats.dealer_probs_cache.bytes;
+        assert!(bytes <= budget, "{} > {}", bytes, budget);
+    }
+
+    #[test]
+    fn test_solver_thread_pool() {
+        let control = EvControl::new();
+        let solver = Solver::new().with_threads(2).unwrap();
+        assert_eq!(solver.current_num_threads(), 2);
+        assert_eq!(solver.install(rayon::current_num_threads), 2);
+        assert!(solver.install(|| rayon::current_thread_index().is_some()));
+
+        let ev = solver
+            .ev::<Ratio<BigInt>>(&game(Rule::evolution_classic()), &control)
+            .unwrap();
+        assert_eq!(ev, game(Rule::evolution_classic()).ev());
+
+        // 個別の行動と PartialEv もプールの中で計算する
+        let game = game(Rule::evolution_classic());
+        assert_eq!(
+            solver.hit_ev::<Ratio<BigInt>>(&game, &control).unwrap(),
+            game.hit_ev(&CancellationToken::new()).unwrap()
+        );
+        assert_eq!(
+            solver.double_ev::<Ratio<BigInt>>(&game, &control).unwrap(),
+            game.double_ev(&CancellationToken::new()).unwrap()
+        );
+        assert_eq!(solver.split_ev::<f64>(&game, &control).unwrap(), None);
+        let deck = Deck::new_from_strs(&vec!["A", "2", "3", "4", "5", "6", "7", "8", "9", "10"]);
+        let pre_round = PreBlackJackGame::new(Rule::evolution_classic(), deck);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sender = std::sync::Mutex::new(sender);
+        let control = EvControl::new().with_progress(move |_| {
+            sender
+                .lock()
+                .unwrap()
+                .send(rayon::current_num_threads())
+                .unwrap();
+        });
+        let partial: PartialEv<Ratio<BigInt>> = solver.partial_ev(&pre_round, &control);
+        assert_eq!(partial.into_result().unwrap(), pre_round.ev());
+        drop(control);
+        let num_threads: Vec<usize> = receiver.iter().collect();
+        assert!(!num_threads.is_empty());
+        assert!(num_threads.iter().all(|&n| n == 2));
+
+        // プールは共有できる
+        let shared = Solver::new().with_thread_pool(Arc::clone(solver.thread_pool().unwrap()));
+        assert!(Arc::ptr_eq(
+            shared.thread_pool().unwrap(),
+            solver.thread_pool().unwrap()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_solver_async() {
+        let solver = Arc::new(Solver::new().with_threads(2).unwrap());
+        let game = game(Rule::evolution_classic());
+
+        let ev = solver
+            .ev_async::<Ratio<BigInt>>(&game, &EvControl::new())
+            .await
+            .unwrap();
+        assert_eq!(ev, game.ev());
+        assert!(!solver.exact_hit_ev.is_empty());
+
+        // Solver のプールで計算される
+        let deck = Deck::new_from_strs(&vec!["A", "2", "3", "4", "5", "6", "7", "8", "9", "10"]);
+        let pre_round = PreBlackJackGame::new(Rule::evolution_classic(), deck);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sender = std::sync::Mutex::new(sender);
+        let control = EvControl::new().with_progress(move |_| {
+            let name = std::thread::current().name().map(str::to_string);
+            sender.lock().unwrap().send(name).unwrap();
+        });
+        let partial: PartialEv<f64> = solver.partial_ev_async(&pre_round, &control).await;
+        assert!(partial.is_complete());
+        drop(control);
+        let names: Vec<Option<String>> = receiver.iter().collect();
+        assert!(!names.is_empty());
+        assert!(names.iter().all(|name| name
+            .as_deref()
+            .is_some_and(|name| name.starts_with("bjc-solver-"))));
+    }
+
+    #[test]
+    fn test_solver_dealer_engine() {
+        let control = EvControl::new();
+        let recursive = Solver::new().with_dealer_engine(DealerProbEngine::Recursive);
+        let pattern = Solver::new();
+        assert_eq!(recursive.dealer_engine(), DealerProbEngine::Recursive);
+        assert_eq!(pattern.dealer_engine(), DealerProbEngine::Pattern);
+
+        // Solver ごとにエンジンを選べて、キャッシュは共有しない
+        let deck = Deck::new(1).remove(Card::Face);
+        assert_eq!(
+            recursive.dealer_probs(&deck).unwrap(),
+            pattern.dealer_probs(&deck).unwrap()
+        );
+        assert!(!Arc::ptr_eq(&recursive.dealer_probs, &pattern.dealer_probs));
+        assert!(!Arc::ptr_eq(
+            &recursive.dealer_probs,
+            &Solver::global().dealer_probs
+        ));
+
+        let game = game(Rule::evolution_classic());
+        assert_eq!(
+            recursive.ev::<Ratio<BigInt>>(&game, &control).unwrap(),
+            game.ev()
+        );
+    }
+
+    #[test]
+    fn test_solver_dealer_patterns() {
+        let control = EvControl::new();
+        let bytes = DealerHandPatterns::standard().to_bytes().unwrap();
+        let loaded = Arc::new(DealerHandPatterns::from_bytes(&bytes).unwrap());
+        let solver = Solver::new().with_dealer_patterns(Arc::clone(&loaded));
+
+        let ev = solver
+            .ev::<Ratio<BigInt>>(&game(Rule::evolution_classic()), &control)
+            .unwrap();
+        assert_eq!(ev, game(Rule::evolution_classic()).ev());
+
+        // 読み込んだテーブルが使われる. 10 のアップカードのバストを消したテーブル
+        let mut no_bust = (*loaded).clone();
+        no_bust.face.burst.clear();
+        let solver = Solver::new().with_dealer_patterns(Arc::new(no_bust));
+        let deck = Deck::new(1).remove(Card::Face);
+        assert_eq!(
+            solver.dealer_probs(&deck).unwrap().face.bust,
+            Ratio::new(0, 1)
+        );
+        assert_ne!(deck.dealer_probs().unwrap().face.bust, Ratio::new(0, 1));
+    }
+
+    #[test]
+    fn test_solver_per_rule() {
+        let control = EvControl::new();
+        let mut peek_rule = Rule::evolution_classic();
+        peek_rule.dealer_peek = true;
+
+        let classic = Solver::new();
+        let peek = Solver::new();
+        let classic_ev = classic
+            .ev::<f64>(&game(Rule::evolution_classic()), &control)
+            .unwrap();
+        let peek_ev = peek.ev::<f64>(&game(peek_rule.clone()), &control).unwrap();
+
+        assert_eq!(classic_ev, game(Rule::evolution_classic()).ev_as::<f64>());
+        assert_eq!(peek_ev, game(peek_rule).ev_as::<f64>());
+    }
+}
@@ -0,0 +1,351 @@
+use super::Solver;
+use crate::cache::BoundedCache;
+use crate::game::{BlackJackGame, GameKey};
+use crate::models::{Card, DealerHandProb, DealerProbEngine, Deck, Hand, Rule};
+
+use bincode::Options;
+use num::bigint::BigInt;
+use num::rational::Ratio;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+// フォーマット
+// magic "BJEV" | version: u16 | bincode の CacheFile | crc32: u32
+// crate のバージョンか Rule かディーラーのテーブルかエンジンが違うファイルは読み込まない
+const MAGIC: &[u8; 4] = b"BJEV";
+pub const EV_CACHE_FORMAT_VERSION: u16 = 3;
+
+const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// bincode::serialize と同じ固定長のエンコード. 読み込みはファイルの長さで制限して、
+// 壊れた長さのフィールドで大きなメモリを確保しないようにする
+fn bincode_options(limit: u64) -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_limit(limit)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.to_string())
+}
+
+// rule はヘッダーに1つだけ持つので、キーからは外す
+#[derive(Serialize, Deserialize)]
+struct StoredKey {
+    dealer_card: Card,
+    player_hand: Hand,
+    deck_cards: Deck,
+    player_card_count: usize,
+}
+
+impl StoredKey {
+    fn new(game: &BlackJackGame) -> Self {
+        StoredKey {
+            dealer_card: game.dealer_card,
+            player_hand: game.player_hand.clone(),
+            deck_cards: game.deck_cards.clone(),
+            player_card_count: game.player_card_count,
+        }
+    }
+
+    fn into_game(self, rule: &Arc<Rule>) -> BlackJackGame {
+        BlackJackGame {
+            rule: Arc::clone(rule),
+            dealer_card: self.dealer_card,
+            player_hand: self.player_hand,
+            deck_cards: self.deck_cards,
+            player_card_count: self.player_card_count,
+        }
+    }
+}
+
+// f64 のキャッシュは計算し直しても速いので、厳密な値だけ保存する
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    crate_version: String,
+    rule: Rule,
+    // Solver::with_dealer_patterns のテーブルの fingerprint. 組み込みのテーブルなら None
+    dealer_patterns: Option<u32>,
+    dealer_engine: DealerProbEngine,
+    stand_ev: Vec<(StoredKey, Ratio<BigInt>)>,
+    hit_ev: Vec<(StoredKey, Ratio<BigInt>)>,
+    // ディーラーの分布は Rule によらない
+    dealer_probs: Vec<(Deck, DealerHandProb)>,
+}
+
+impl Solver {
+    // rule のエントリだけを保存する
+    pub fn save_cache<P: AsRef<Path>>(&self, path: P, rule: &Rule) -> io::Result<()> {
+        let entries = |cache: &BoundedCache<GameKey, Ratio<BigInt>>| {
+            cache
+                .entries()
+                .into_iter()
+                .filter(|(key, _)| **key.rule() == *rule)
+                .map(|(key, ev)| (StoredKey::new(&key.to_game()), ev))
+                .collect()
+        };
+        let cache_file = CacheFile {
+            crate_version: CRATE_VERSION.to_string(),
+            rule: rule.clone(),
+            dealer_patterns: self.dealer_patterns_fingerprint(),
+            dealer_engine: self.dealer_engine,
+            stand_ev: entries(&self.exact_stand_ev),
+            hit_ev: entries(&self.exact_hit_ev),
+            dealer_probs: self
+                .dealer_probs
+                .entries()
+                .into_iter()
+                .map(|(deck, probs)| (deck, (*probs).clone()))
+                .collect(),
+        };
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&EV_CACHE_FORMAT_VERSION.to_le_bytes());
+        bincode_options(u64::MAX)
+            .serialize_into(&mut bytes, &cache_file)
+            .map_err(io::Error::other)?;
+        let checksum = crc32fast::hash(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+
+        // 書き込み中に落ちても壊れたファイルが残らないように、一時ファイルから rename する
+        // 拡張子を置き換えると cache.bin と cache.tmp がぶつかるので、ファイル名の後ろに足す
+        let path = path.as_ref();
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+        fs::write(&tmp_path, bytes)?;
+        fs::rename(tmp_path, path)
+    }
+
+    // 読み込んだエントリ数を返す. crate のバージョンか Rule が違えば InvalidData
+    pub fn load_cache<P: AsRef<Path>>(&self, path: P, rule: &Rule) -> io::Result<usize> {
+        let bytes = fs::read(path)?;
+        if bytes.len() < MAGIC.len() + 2 + 4 {
+            return Err(invalid_data("ev cache file is too short"));
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - 4);
+        if crc32fast::hash(body).to_le_bytes() != checksum {
+            return Err(invalid_data("ev cache checksum mismatch"));
+        }
+        if &body[..MAGIC.len()] != MAGIC {
+            return Err(invalid_data("not an ev cache file"));
+        }
+        let version = u16::from_le_bytes([body[MAGIC.len()], body[MAGIC.len() + 1]]);
+        if version != EV_CACHE_FORMAT_VERSION {
+            return Err(invalid_data(&format!(
+                "unsupported ev cache format version {}",
+                version
+            )));
+        }
+
+        let payload = &body[MAGIC.len() + 2..];
+        let cache_file: CacheFile = bincode_options(payload.len() as u64)
+            .deserialize(payload)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        if cache_file.crate_version != CRATE_VERSION {
+            return Err(invalid_data(&format!(
+                "ev cache was written by version {}",
+                cache_file.crate_version
+            )));
+        }
+        if cache_file.rule != *rule {
+            return Err(invalid_data("ev cache was written for a different rule"));
+        }
+        if cache_file.dealer_patterns != self.dealer_patterns_fingerprint()
+            || cache_file.dealer_engine != self.dealer_engine
+        {
+            return Err(invalid_data(
+                "ev cache was written with different dealer patterns or engine",
+            ));
+        }
+
+        let rule = Arc::new(cache_file.rule);
+        let count =
+            cache_file.stand_ev.len() + cache_file.hit_ev.len() + cache_file.dealer_probs.len();
+        for (key, ev) in cache_file.stand_ev {
+            self.exact_stand_ev
+                .insert(key.into_game(&rule).cache_key(), ev);
+        }
+        for (key, ev) in cache_file.hit_ev {
+            self.exact_hit_ev
+                .insert(key.into_game(&rule).cache_key(), ev);
+        }
+        for (deck, probs) in cache_file.dealer_probs {
+            self.dealer_probs.insert(deck, Arc::new(probs));
+        }
+        Ok(count)
+    }
+
+    fn dealer_patterns_fingerprint(&self) -> Option<u32> {
+        self.dealer_patterns
+            .as_ref()
+            .map(|dealer_patterns| dealer_patterns.fingerprint())
+    }
+
+    // 起動時用. ファイルがない、または使えない場合は空の Solver になる
+    // 使えなかった理由は2つ目で返す. ファイルがないだけなら None
+    pub fn with_cache_file<P: AsRef<Path>>(path: P, rule: &Rule) -> (Self, Option<io::Error>) {
+        let solver = Solver::new();
+        match solver.load_cache(path, rule) {
+            Ok(_) => (solver, None),
+            Err(e) => {
+                solver.clear_cache();
+                let error = (e.kind() != ErrorKind::NotFound).then_some(e);
+                (solver, error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::EvControl;
+    use crate::models::{DealerHandPatterns, HandTotal};
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("bjc_{}_{}.bin", name, std::process::id()))
+    }
+
+    fn game(rule: &Rule) -> BlackJackGame {
+        BlackJackGame::new(
+            rule.clone(),
+            Card::Face,
+            Hand::Hard(HandTotal::Value(12)),
+            Deck::new(1),
+            1,
+        )
+    }
+
+    #[test]
+    fn test_save_load() {
+        let control = EvControl::new();
+        let rule = Rule::evolution_classic();
+        let path = temp_path("ev_cache");
+
+        let solver = Solver::new();
+        let ev = solver.ev::<Ratio<BigInt>>(&game(&rule), &control).unwrap();
+        solver.save_cache(&path, &rule).unwrap();
+
+        let (loaded, error) = Solver::with_cache_file(&path, &rule);
+        fs::remove_file(&path).unwrap();
+
+        assert!(error.is_none());
+
+        assert_eq!(loaded.exact_hit_ev.len(), solver.exact_hit_ev.len());
+        assert_eq!(loaded.exact_stand_ev.len(), solver.exact_stand_ev.len());
+        assert_eq!(loaded.dealer_probs.len(), solver.dealer_probs.len());
+        assert_eq!(
+            loaded.ev::<Ratio<BigInt>>(&game(&rule), &control).unwrap(),
+            ev
+        );
+    }
+
+    #[test]
+    fn test_load_other_rule() {
+        let control = EvControl::new();
+        let rule = Rule::evolution_classic();
+        let path = temp_path("ev_cache_other_rule");
+
+        let solver = Solver::new();
+        solver.ev::<f64>(&game(&rule), &control).unwrap();
+        solver.save_cache(&path, &rule).unwrap();
+
+        let mut other_rule = rule.clone();
+        other_rule.dealer_peek = true;
+        let err = Solver::new().load_cache(&path, &other_rule).unwrap_err();
+        let (loaded, error) = Solver::with_cache_file(&path, &other_rule);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(error.unwrap().kind(), ErrorKind::InvalidData);
+        assert!(loaded.dealer_probs.is_empty());
+    }
+
+    #[test]
+    fn test_load_other_dealer_patterns() {
+        let control = EvControl::new();
+        let rule = Rule::evolution_classic();
+        let path = temp_path("ev_cache_other_patterns");
+
+        let mut patterns = DealerHandPatterns::standard().clone();
+        patterns.face.burst.clear();
+        let solver = Solver::new().with_dealer_patterns(Arc::new(patterns));
+        solver.ev::<Ratio<BigInt>>(&game(&rule), &control).unwrap();
+        solver.save_cache(&path, &rule).unwrap();
+
+        let err = Solver::new().load_cache(&path, &rule).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("dealer patterns"), "{}", err);
+    }
+
+    #[test]
+    fn test_load_other_dealer_engine() {
+        let control = EvControl::new();
+        let rule = Rule::evolution_classic();
+        let path = temp_path("ev_cache_other_engine");
+
+        let solver = Solver::new().with_dealer_engine(DealerProbEngine::Recursive);
+        solver.ev::<f64>(&game(&rule), &control).unwrap();
+        solver.save_cache(&path, &rule).unwrap();
+
+        let err = Solver::new().load_cache(&path, &rule).unwrap_err();
+        let loaded = Solver::new()
+            .with_dealer_engine(DealerProbEngine::Recursive)
+            .load_cache(&path, &rule);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(loaded.unwrap() > 0);
+    }
+
+    #[test]
+    fn test_load_oversized_length() {
+        // crate_version の長さだけが巨大なファイル. チェックサムは正しい
+        let path = temp_path("ev_cache_oversized");
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&EV_CACHE_FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&(1u64 << 40).to_le_bytes());
+        bytes.extend_from_slice(CRATE_VERSION.as_bytes());
+        let checksum = crc32fast::hash(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        fs::write(&path, bytes).unwrap();
+
+        let err = Solver::new()
+            .load_cache(&path, &Rule::evolution_classic())
+            .unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let (solver, error) =
+            Solver::with_cache_file(temp_path("missing"), &Rule::evolution_classic());
+        assert!(error.is_none());
+        assert!(solver.exact_hit_ev.is_empty());
+    }
+
+    #[test]
+    fn test_save_keeps_file_with_tmp_extension() {
+        let rule = Rule::evolution_classic();
+        let dir = std::env::temp_dir().join(format!("bjc_tmp_name_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("cache.bin");
+        let other = dir.join("cache.tmp");
+        fs::write(&other, b"other file").unwrap();
+
+        Solver::new().save_cache(&path, &rule).unwrap();
+        let other_bytes = fs::read(&other).unwrap();
+        let loaded = Solver::new().load_cache(&path, &rule);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(other_bytes, b"other file");
+        assert_eq!(loaded.unwrap(), 0);
+    }
+}
@@ -0,0 +1,198 @@
+use super::Solver;
+use crate::cache::CacheStats;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Phase {
+    DealerProbs,
+    Stand,
+    Hit,
+    Double,
+    Split,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 5] = [
+        Phase::DealerProbs,
+        Phase::Stand,
+        Phase::Hit,
+        Phase::Double,
+        Phase::Split,
+    ];
+}
+
+// 時間はそのフェーズ自身の時間. 中で呼んだフェーズ (hit の中の stand や、再帰した hit) は除く
+// スレッドごとに計って足すので、合計は経過時間 x スレッド数を超えない
+// par_iter で他のスレッドを待っている間は、待っている側のフェーズに入る
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PhaseStats {
+    pub calls: u64,
+    pub total: Duration,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct PhaseTimer {
+    calls: AtomicU64,
+    nanos: AtomicU64,
+}
+
+impl PhaseTimer {
+    fn stats(&self) -> PhaseStats {
+        PhaseStats {
+            calls: self.calls.load(Ordering::Relaxed),
+            total: Duration::from_nanos(self.nanos.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn reset(&self) {
+        self.calls.store(0, Ordering::Relaxed);
+        self.nanos.store(0, Ordering::Relaxed);
+    }
+}
+
+// 計測中のフェーズ. 開始時刻と、その中で呼んだフェーズにかかった時間
+thread_local! {
+    static PHASE_STACK: RefCell<Vec<(Instant, Duration)>> = const { RefCell::new(Vec::new()) };
+}
+
+// panic でも積んだフェーズを下ろす
+struct PhaseFrame;
+
+impl PhaseFrame {
+    fn enter() -> Self {
+        PHASE_STACK.with(|stack| stack.borrow_mut().push((Instant::now(), Duration::ZERO)));
+        PhaseFrame
+    }
+}
+
+impl Drop for PhaseFrame {
+    fn drop(&mut self) {
+        PHASE_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            if let Some((start, _)) = stack.pop() {
+                if let Some((_, children)) = stack.last_mut() {
+                    *children += start.elapsed();
+                }
+            }
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SolverStats {
+    // 厳密な値と f64 の合計
+    pub stand_cache: CacheStats,
+    pub hit_cache: CacheStats,
+    pub dealer_probs_cache: CacheStats,
+    pub phases: BTreeMap<Phase, PhaseStats>,
+}
+
+impl SolverStats {
+    pub fn phase(&self, phase: Phase) -> PhaseStats {
+        self.phases.get(&phase).copied().unwrap_or_default()
+    }
+}
+
+impl Solver {
+    pub fn stats(&self) -> SolverStats {
+        SolverStats {
+            stand_cache: self.exact_stand_ev.stats() + self.f64_stand_ev.stats(),
+            hit_cache: self.exact_hit_ev.stats() + self.f64_hit_ev.stats(),
+            dealer_probs_cache: self.dealer_probs.stats(),
+            phases: Phase::ALL
+                .iter()
+                .map(|&phase| (phase, self.phase_timer(phase).stats()))
+                .collect(),
+        }
+    }
+
+    // カウンターと時間を0に戻す. キャッシュの中身は消さない
+    pub fn reset_stats(&self) {
+        self.exact_stand_ev.reset_stats();
+        self.exact_hit_ev.reset_stats();
+        self.f64_stand_ev.reset_stats();
+        self.f64_hit_ev.reset_stats();
+        self.dealer_probs.reset_stats();
+        for phase in Phase::ALL {
+            self.phase_timer(phase).reset();
+        }
+    }
+
+    pub(crate) fn time<T>(&self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        let frame = PhaseFrame::enter();
+        let result = f();
+        let own = PHASE_STACK.with(|stack| {
+            let stack = stack.borrow();
+            let (start, children) = stack.last().expect("phase frame was pushed");
+            start.elapsed().saturating_sub(*children)
+        });
+        drop(frame);
+
+        let timer = self.phase_timer(phase);
+        timer.calls.fetch_add(1, Ordering::Relaxed);
+        timer
+            .nanos
+            .fetch_add(own.as_nanos() as u64, Ordering::Relaxed);
+        result
+    }
+
+    fn phase_timer(&self, phase: Phase) -> &PhaseTimer {
+        &self.phase_timers[phase as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{BlackJackGame, EvControl};
+    use crate::models::{Card, Deck, Hand, Rule};
+
+    #[test]
+    fn test_stats() {
+        let control = EvControl::new();
+        let threads = 2;
+        let solver = Solver::new().with_threads(threads).unwrap();
+        let game = BlackJackGame::new(
+            Rule::evolution_classic(),
+            Card::N6,
+            Hand::Pair(Card::N8),
+            Deck::new(1),
+            2,
+        );
+
+        let start = Instant::now();
+        solver.action_ev::<f64>(&game, &control).unwrap();
+        let elapsed = start.elapsed();
+        let stats = solver.stats();
+
+        assert!(stats.hit_cache.misses > 0);
+        assert!(stats.stand_cache.hits > 0);
+        assert_eq!(stats.hit_cache.entries, solver.f64_hit_ev.len());
+        assert!(stats.dealer_probs_cache.bytes > 0);
+        for phase in Phase::ALL {
+            assert!(stats.phase(phase).calls > 0, "phase: {:?}", phase);
+        }
+        // 再帰した hit や hit の中の stand を二重に数えない
+        let total: Duration = stats.phases.values().map(|phase| phase.total).sum();
+        assert!(
+            total <= elapsed * threads as u32,
+            "{:?} > {:?} x {}",
+            total,
+            elapsed,
+            threads
+        );
+
+        // 2回目はキャッシュから
+        solver.reset_stats();
+        solver.action_ev::<f64>(&game, &control).unwrap();
+        let stats = solver.stats();
+
+        assert_eq!(stats.hit_cache.misses, 0);
+        assert_eq!(stats.phase(Phase::Hit).calls, 0);
+        assert_eq!(stats.phase(Phase::DealerProbs).calls, 0);
+    }
+}
@@ -1,3 +1,8 @@
 // pub mod game;
+pub mod cache;
+pub mod error;
 pub mod game;
 pub mod models;
+pub mod strategy;
+
+pub use error::{Error, Result};
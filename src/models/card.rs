@@ -30,7 +30,7 @@ impl Ord for Card {
 }
 
 impl Card {
-    pub(crate) const ALL: [Card; 10] = [
+    pub const ALL: [Card; 10] = [
         Card::Ace,
         Card::N2,
         Card::N3,
@@ -58,6 +58,7 @@ impl Card {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "A" | "1" => Some(Card::Ace),
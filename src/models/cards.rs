@@ -2,6 +2,7 @@ use smallvec::SmallVec;
 use std::cmp::Ordering;
 
 use crate::models::{Card, Deck};
+use crate::Error;
 
 #[derive(PartialEq, Debug, Eq, Hash, Clone)]
 pub struct Cards {
@@ -30,6 +31,12 @@ impl PartialOrd for Cards {
     }
 }
 
+impl Default for Cards {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Cards {
     pub fn new() -> Self {
         Cards {
@@ -51,6 +58,15 @@ impl Cards {
         Cards { cards }
     }
 
+    // 不明な文字列があれば InvalidHand. new_from_strs は読み飛ばす
+    pub fn try_new_from_strs(strs: &Vec<&str>) -> Result<Self, Error> {
+        strs.iter()
+            .map(|s| {
+                Card::from_str(s).ok_or_else(|| Error::InvalidHand(format!("unknown card {:?}", s)))
+            })
+            .collect()
+    }
+
     pub fn add_mut(&mut self, card: Card) {
         self.cards.push(card);
     }
@@ -115,6 +131,16 @@ mod tests {
     use std::collections::BTreeSet;
     use std::vec;
 
+    #[test]
+    fn test_try_new_from_strs() {
+        let cards = Cards::try_new_from_strs(&vec!["A", "10"]).unwrap();
+        assert_eq!(cards.cards.as_slice(), &[Card::Ace, Card::Face]);
+        assert_eq!(
+            Cards::try_new_from_strs(&vec!["A", "X"]),
+            Err(Error::InvalidHand("unknown card \"X\"".to_string()))
+        );
+    }
+
     #[test]
     fn test_from_iter_empty() {
         let card_iter = vec![];
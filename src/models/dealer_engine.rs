@@ -0,0 +1,288 @@
+use crate::models::{Card, DealerHandProb, DealerTotalProb, Deck, HandTotal};
+use crate::Error;
+
+use num::rational::Ratio;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+// ディーラーの分布を計算するエンジン. Solver::with_dealer_engine で Solver ごとに選ぶ
+// Deck::dealer_probs とデフォルトの Solver は Pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum DealerProbEngine {
+    // dealer_pattern の多重集合パターンを全て足し合わせる
+    #[default]
+    Pattern,
+    // 残りのdeckからディーラーの引き方を再帰的にたどる. (hand, deck) でメモ化する
+    Recursive,
+}
+
+// ディーラーの手札. soft は A を 11 として数えているかどうか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DealerHand {
+    total: usize,
+    soft: bool,
+}
+
+impl DealerHand {
+    fn new(card: Card) -> Self {
+        DealerHand {
+            total: card.value(),
+            soft: card == Card::Ace,
+        }
+    }
+
+    fn add(&self, card: Card) -> Self {
+        let mut total = self.total + card.value();
+        let mut soft = self.soft;
+        if card == Card::Ace {
+            if soft {
+                total -= 10;
+            } else {
+                soft = true;
+            }
+        }
+        if total > 21 && soft {
+            total -= 10;
+            soft = false;
+        }
+        DealerHand { total, soft }
+    }
+}
+
+// ディーラーの再帰で集める値. 合計ごとの分布 (DealerTotalProb) か、合計と枚数ごとの分布 (DealerCardCountProb)
+// 引き方のたどり方は dealer_draw の1か所だけにして、集め方だけを変える
+trait DealerOutcome: Clone + Default {
+    // これ以上引かずに total で終わったときの値
+    fn finished(total: HandTotal) -> Self;
+
+    // 1枚引いた後の値 next を、その1枚を引く確率 prob で重み付けして足す
+    fn add_drawn(&mut self, next: &Self, prob: &Ratio<u128>) -> Result<(), Error>;
+}
+
+impl DealerOutcome for DealerTotalProb {
+    fn finished(total: HandTotal) -> Self {
+        let mut dealer_total_prob = DealerTotalProb::new();
+        dealer_total_prob.insert(&total, Ratio::new(1, 1));
+        dealer_total_prob
+    }
+
+    fn add_drawn(&mut self, next: &Self, prob: &Ratio<u128>) -> Result<(), Error> {
+        self.add_all_scaled(next, prob)
+    }
+}
+
+// key の (hand, deck) からの結果. アップカードが違っても同じなので共有してよい
+type DealerMemo<T> = HashMap<(DealerHand, Deck), T>;
+
+impl Deck {
+    pub(crate) fn calc_dealer_hand_prob_recursive(&self) -> Result<DealerHandProb, Error> {
+        let mut dealer_hand_prob = DealerHandProb::new();
+        let mut memo = DealerMemo::new();
+
+        for card in Card::ALL {
+            dealer_hand_prob.insert(card, self.dealer_outcome(card, &mut memo)?);
+        }
+        Ok(dealer_hand_prob)
+    }
+
+    // selfはアップカードを除いた残りのdeck
+    // 2枚目だけはブラックジャックの判定があるので、ここで展開する
+    fn dealer_outcome<T: DealerOutcome>(
+        &self,
+        up_card: Card,
+        memo: &mut DealerMemo<T>,
+    ) -> Result<T, Error> {
+        let up_hand = DealerHand::new(up_card);
+        let mut outcome = T::default();
+
+        for rank in self.remaining_ranks() {
+            let prob = draw_probability_u128(self, rank);
+            let hand = up_hand.add(rank);
+
+            let next = if hand.total == 21 {
+                T::finished(HandTotal::BlackJack)
+            } else {
+                self.remove(rank).dealer_draw(hand, memo)?
+            };
+            outcome.add_drawn(&next, &prob)?;
+        }
+        Ok(outcome)
+    }
+
+    // ソフト17でスタンド. deckが尽きた場合は、どの合計にもならない (パターン側と同じく確率0)
+    fn dealer_draw<T: DealerOutcome>(
+        &self,
+        hand: DealerHand,
+        memo: &mut DealerMemo<T>,
+    ) -> Result<T, Error> {
+        if hand.total > 21 {
+            return Ok(T::finished(HandTotal::Burst));
+        }
+        if hand.total >= 17 {
+            return Ok(T::finished(HandTotal::Value(hand.total)));
+        }
+
+        let key = (hand, self.clone());
+        if let Some(cached) = memo.get(&key) {
+            return Ok(cached.clone());
+        }
+
+        let mut outcome = T::default();
+        for rank in self.remaining_ranks() {
+            let prob = draw_probability_u128(self, rank);
+            let next = self.remove(rank).dealer_draw(hand.add(rank), memo)?;
+            outcome.add_drawn(&next, &prob)?;
+        }
+
+        memo.insert(key, outcome.clone());
+        Ok(outcome)
+    }
+}
+
+// ディーラーの最終的な枚数 (アップカードを含む) ごとの分布
+// Buster Blackjack のように、何枚でバストしたかで配当が変わるサイドベット用
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct DealerCardCountProb {
+    pub by_card_count: BTreeMap<usize, DealerTotalProb>,
+}
+
+impl DealerCardCountProb {
+    pub fn get(&self, card_count: usize) -> Option<&DealerTotalProb> {
+        self.by_card_count.get(&card_count)
+    }
+
+    // card_count 枚でバストする確率
+    pub fn bust_with(&self, card_count: usize) -> Ratio<u128> {
+        self.get(card_count)
+            .map_or(Ratio::new(0, 1), |prob| prob.bust)
+    }
+
+    // 枚数をまとめた分布. dealer_total_prob と同じになる
+    pub fn total_prob(&self) -> Result<DealerTotalProb, Error> {
+        let mut dealer_total_prob = DealerTotalProb::new();
+        for prob in self.by_card_count.values() {
+            dealer_total_prob.add_all_scaled(prob, &Ratio::new(1, 1))?;
+        }
+        Ok(dealer_total_prob)
+    }
+
+    fn add_all_scaled(
+        &mut self,
+        other: &DealerCardCountProb,
+        card_offset: usize,
+        prob: &Ratio<u128>,
+    ) -> Result<(), Error> {
+        for (card_count, total_prob) in other.by_card_count.iter() {
+            self.by_card_count
+                .entry(card_count + card_offset)
+                .or_default()
+                .add_all_scaled(total_prob, prob)?;
+        }
+        Ok(())
+    }
+}
+
+// by_card_count のキーは、その場面からあと何枚引いたか
+impl DealerOutcome for DealerCardCountProb {
+    fn finished(total: HandTotal) -> Self {
+        DealerCardCountProb {
+            by_card_count: BTreeMap::from([(0, DealerTotalProb::finished(total))]),
+        }
+    }
+
+    fn add_drawn(&mut self, next: &Self, prob: &Ratio<u128>) -> Result<(), Error> {
+        self.add_all_scaled(next, 1, prob)
+    }
+}
+
+impl Deck {
+    // selfはアップカードを除いた残りのdeck
+    pub fn dealer_card_count_prob(&self, up_card: Card) -> Result<DealerCardCountProb, Error> {
+        let drawn: DealerCardCountProb = self.dealer_outcome(up_card, &mut DealerMemo::new())?;
+        // アップカードの1枚を足す
+        let mut dealer_card_count_prob = DealerCardCountProb::default();
+        dealer_card_count_prob.add_all_scaled(&drawn, 1, &Ratio::new(1, 1))?;
+        Ok(dealer_card_count_prob)
+    }
+}
+
+fn draw_probability_u128(deck: &Deck, rank: Card) -> Ratio<u128> {
+    Ratio::new(deck.count(rank) as u128, deck.total_cards() as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recursive_matches_pattern() {
+        for deck in [
+            Deck::new(1),
+            Deck::new(2),
+            Deck::new(6),
+            Deck::new(8),
+            Deck::new_from_strs(&vec!["A", "A", "2", "6", "6", "T", "T"]),
+            Deck::new(1).remove_deck(&Deck::new_from_strs(&vec!["T", "T", "T", "5", "A"])),
+        ] {
+            assert_eq!(
+                deck.calc_dealer_hand_prob_with(DealerProbEngine::Recursive)
+                    .unwrap(),
+                deck.calc_dealer_hand_prob_with(DealerProbEngine::Pattern)
+                    .unwrap(),
+                "deck: {:?}",
+                deck
+            );
+        }
+    }
+
+    #[test]
+    fn test_recursive_sum() {
+        let deck = Deck::new(1);
+        let prob = deck
+            .calc_dealer_hand_prob_with(DealerProbEngine::Recursive)
+            .unwrap();
+
+        for card in Card::ALL {
+            let total = prob.get(card).iter().map(|(_, p)| *p).sum::<Ratio<u128>>();
+            assert_eq!(total, Ratio::new(1, 1), "card: {:?}", card);
+        }
+    }
+
+    #[test]
+    fn test_dealer_card_count_prob() {
+        let deck = Deck::new(1);
+
+        for card in Card::ALL {
+            let deck = deck.remove(card);
+            let by_count = deck.dealer_card_count_prob(card).unwrap();
+            let total_prob = by_count.total_prob().unwrap();
+
+            assert_eq!(total_prob, deck.dealer_total_prob(card).unwrap());
+            // 同じ再帰で集めた合計ごとの分布とも一致する
+            assert_eq!(
+                total_prob,
+                *deck
+                    .calc_dealer_hand_prob_with(DealerProbEngine::Recursive)
+                    .unwrap()
+                    .get(card)
+            );
+            // 2枚目でバストすることはない
+            assert_eq!(by_count.bust_with(2), Ratio::new(0, 1));
+            for (card_count, prob) in by_count.by_card_count.iter() {
+                if *card_count != 2 {
+                    assert_eq!(prob.black_jack, Ratio::new(0, 1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dealer_card_count_prob_small_deck() {
+        // 6 → 6 → 10 で3枚でバストするか、6 → 10 → 6 で3枚でバスト
+        let deck = Deck::new_from_strs(&vec!["6", "T"]);
+        let by_count = deck.dealer_card_count_prob(Card::N6).unwrap();
+
+        assert_eq!(by_count.bust_with(3), Ratio::new(1, 1));
+        assert_eq!(by_count.bust_with(4), Ratio::new(0, 1));
+    }
+}
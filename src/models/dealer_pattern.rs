@@ -1,8 +1,10 @@
-use crate::models::{Card, Deck, HandTotal};
+use crate::models::{Card, Cards, Deck, Hand, HandTotal};
 use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
 use std::collections::BTreeMap;
 use std::sync::LazyLock;
 mod ace_patterns;
+mod binary;
 mod face_patterns;
 mod n2_patterns;
 mod n3_patterns;
@@ -13,21 +15,23 @@ mod n7_patterns;
 mod n8_patterns;
 mod n9_patterns;
 
+pub use binary::DEALER_PATTERN_FORMAT_VERSION;
+
 // 一覧であり、キャッシュではない
 static DEALER_HAND_PATTERNS: LazyLock<DealerHandPatterns> = LazyLock::new(|| DealerHandPatterns {
-    ace: ace_patterns::ACE_PATTERNS.clone(),
-    n2: n2_patterns::N2_PATTERNS.clone(),
-    n3: n3_patterns::N3_PATTERNS.clone(),
-    n4: n4_patterns::N4_PATTERNS.clone(),
-    n5: n5_patterns::N5_PATTERNS.clone(),
-    n6: n6_patterns::N6_PATTERNS.clone(),
-    n7: n7_patterns::N7_PATTERNS.clone(),
-    n8: n8_patterns::N8_PATTERNS.clone(),
-    n9: n9_patterns::N9_PATTERNS.clone(),
-    face: face_patterns::FACE_PATTERNS.clone(),
+    ace: ace_patterns::ace_patterns(),
+    n2: n2_patterns::n2_patterns(),
+    n3: n3_patterns::n3_patterns(),
+    n4: n4_patterns::n4_patterns(),
+    n5: n5_patterns::n5_patterns(),
+    n6: n6_patterns::n6_patterns(),
+    n7: n7_patterns::n7_patterns(),
+    n8: n8_patterns::n8_patterns(),
+    n9: n9_patterns::n9_patterns(),
+    face: face_patterns::face_patterns(),
 });
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct DealerHandDeckMap {
     pub value_17: BTreeMap<Deck, usize>,
     pub value_18: BTreeMap<Deck, usize>,
@@ -54,13 +58,54 @@ impl DealerHandDeckMap {
     //     *count += 1;
     // }
 
-    pub(crate) fn find(first_rank: Card, sum_number: &HandTotal) -> &'static BTreeMap<Deck, usize> {
-        &DEALER_HAND_PATTERNS.get(first_rank, sum_number)
+    pub fn empty() -> Self {
+        DealerHandDeckMap {
+            value_17: BTreeMap::new(),
+            value_18: BTreeMap::new(),
+            value_19: BTreeMap::new(),
+            value_20: BTreeMap::new(),
+            value_21: BTreeMap::new(),
+            burst: BTreeMap::new(),
+            blackjack: BTreeMap::new(),
+        }
+    }
+
+    pub fn get(&self, hand_total: &HandTotal) -> &BTreeMap<Deck, usize> {
+        match hand_total {
+            HandTotal::Value(17) => &self.value_17,
+            HandTotal::Value(18) => &self.value_18,
+            HandTotal::Value(19) => &self.value_19,
+            HandTotal::Value(20) => &self.value_20,
+            HandTotal::Value(21) => &self.value_21,
+            HandTotal::Burst => &self.burst,
+            HandTotal::BlackJack => &self.blackjack,
+            _ => panic!("Invalid HandTotal"),
+        }
+    }
+
+    pub fn get_mut(&mut self, hand_total: &HandTotal) -> &mut BTreeMap<Deck, usize> {
+        match hand_total {
+            HandTotal::Value(17) => &mut self.value_17,
+            HandTotal::Value(18) => &mut self.value_18,
+            HandTotal::Value(19) => &mut self.value_19,
+            HandTotal::Value(20) => &mut self.value_20,
+            HandTotal::Value(21) => &mut self.value_21,
+            HandTotal::Burst => &mut self.burst,
+            HandTotal::BlackJack => &mut self.blackjack,
+            _ => panic!("Invalid HandTotal"),
+        }
+    }
+
+    // first_rank のアップカードから sum_number になる、引くカードのパターンと並び順の数
+    pub fn find(first_rank: Card, sum_number: &HandTotal) -> &'static BTreeMap<Deck, usize> {
+        DEALER_HAND_PATTERNS.get(first_rank, sum_number)
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct DealerHandPatterns {
+// アップカードごとの DealerHandDeckMap. 組み込みのテーブル (ソフト17スタンド) は standard()
+// 別のルールのテーブルは generate で作り、save / load でファイルにしておける
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct DealerHandPatterns {
     pub ace: DealerHandDeckMap,
     pub n2: DealerHandDeckMap,
     pub n3: DealerHandDeckMap,
@@ -74,46 +119,97 @@ struct DealerHandPatterns {
 }
 
 impl DealerHandPatterns {
-    // pub(crate) fn insert(&mut self, card: Card, hand_total: &HandTotal, deck: &Deck) {
-    //     let dealer_hand_deck_map = match (card, hand_total) {
-    //         (Card::Ace, _) => &mut self.ace,
-    //         (Card::N2, _) => &mut self.n2,
-    //         (Card::N3, _) => &mut self.n3,
-    //         (Card::N4, _) => &mut self.n4,
-    //         (Card::N5, _) => &mut self.n5,
-    //         (Card::N6, _) => &mut self.n6,
-    //         (Card::N7, _) => &mut self.n7,
-    //         (Card::N8, _) => &mut self.n8,
-    //         (Card::N9, _) => &mut self.n9,
-    //         (Card::Face, _) => &mut self.face,
-    //     };
-    //     dealer_hand_deck_map.incr(hand_total, deck);
-    // }
+    pub fn standard() -> &'static DealerHandPatterns {
+        &DEALER_HAND_PATTERNS
+    }
 
-    pub(crate) fn get(&self, card: Card, hand_total: &HandTotal) -> &BTreeMap<Deck, usize> {
-        let dealer_hand_deck_map = match (card, hand_total) {
-            (Card::Ace, _) => &self.ace,
-            (Card::N2, _) => &self.n2,
-            (Card::N3, _) => &self.n3,
-            (Card::N4, _) => &self.n4,
-            (Card::N5, _) => &self.n5,
-            (Card::N6, _) => &self.n6,
-            (Card::N7, _) => &self.n7,
-            (Card::N8, _) => &self.n8,
-            (Card::N9, _) => &self.n9,
-            (Card::Face, _) => &self.face,
-        };
-        match hand_total {
-            HandTotal::Value(17) => &dealer_hand_deck_map.value_17,
-            HandTotal::Value(18) => &dealer_hand_deck_map.value_18,
-            HandTotal::Value(19) => &dealer_hand_deck_map.value_19,
-            HandTotal::Value(20) => &dealer_hand_deck_map.value_20,
-            HandTotal::Value(21) => &dealer_hand_deck_map.value_21,
-            HandTotal::Burst => &dealer_hand_deck_map.burst,
-            HandTotal::BlackJack => &dealer_hand_deck_map.blackjack,
-            _ => panic!("Invalid HandTotal"),
+    pub fn empty() -> Self {
+        DealerHandPatterns {
+            ace: DealerHandDeckMap::empty(),
+            n2: DealerHandDeckMap::empty(),
+            n3: DealerHandDeckMap::empty(),
+            n4: DealerHandDeckMap::empty(),
+            n5: DealerHandDeckMap::empty(),
+            n6: DealerHandDeckMap::empty(),
+            n7: DealerHandDeckMap::empty(),
+            n8: DealerHandDeckMap::empty(),
+            n9: DealerHandDeckMap::empty(),
+            face: DealerHandDeckMap::empty(),
         }
     }
+
+    pub fn get_map(&self, card: Card) -> &DealerHandDeckMap {
+        match card {
+            Card::Ace => &self.ace,
+            Card::N2 => &self.n2,
+            Card::N3 => &self.n3,
+            Card::N4 => &self.n4,
+            Card::N5 => &self.n5,
+            Card::N6 => &self.n6,
+            Card::N7 => &self.n7,
+            Card::N8 => &self.n8,
+            Card::N9 => &self.n9,
+            Card::Face => &self.face,
+        }
+    }
+
+    pub fn get_map_mut(&mut self, card: Card) -> &mut DealerHandDeckMap {
+        match card {
+            Card::Ace => &mut self.ace,
+            Card::N2 => &mut self.n2,
+            Card::N3 => &mut self.n3,
+            Card::N4 => &mut self.n4,
+            Card::N5 => &mut self.n5,
+            Card::N6 => &mut self.n6,
+            Card::N7 => &mut self.n7,
+            Card::N8 => &mut self.n8,
+            Card::N9 => &mut self.n9,
+            Card::Face => &mut self.face,
+        }
+    }
+
+    pub fn get(&self, card: Card, hand_total: &HandTotal) -> &BTreeMap<Deck, usize> {
+        self.get_map(card).get(hand_total)
+    }
+
+    // ディーラーの引き方を全部たどってテーブルを作る. hit_soft_17 ならソフト17でも引く
+    // generate(false) は standard() と同じ
+    pub fn generate(hit_soft_17: bool) -> Self {
+        let mut dealer_hand_patterns = DealerHandPatterns::empty();
+        for up_card in Card::ALL {
+            let mut cards = Cards::from_smallvec(smallvec![up_card]);
+            generate_patterns(
+                dealer_hand_patterns.get_map_mut(up_card),
+                &mut cards,
+                hit_soft_17,
+            );
+        }
+        dealer_hand_patterns
+    }
+}
+
+// cards から引き終わるまでの並びを数える. cards の先頭はアップカード
+// Hand::add は A を足してもソフトにならないので、毎回 Cards から合計を出す
+fn generate_patterns(map: &mut DealerHandDeckMap, cards: &mut Cards, hit_soft_17: bool) {
+    let hand: Hand = cards.clone().into();
+    let hand_total = hand.hand_total();
+    let stands = cards.cards.len() >= 2
+        && match hand_total {
+            HandTotal::Value(17) => !(hit_soft_17 && matches!(hand, Hand::Soft(_))),
+            HandTotal::Value(total) => total > 17,
+            HandTotal::BlackJack | HandTotal::Burst => true,
+        };
+    if stands {
+        let drawn = Deck::new_from_cards(&Cards::from_smallvec(cards.cards[1..].into()));
+        *map.get_mut(&hand_total).entry(drawn).or_insert(0) += 1;
+        return;
+    }
+
+    for card in Card::ALL {
+        cards.cards.push(card);
+        generate_patterns(map, cards, hit_soft_17);
+        cards.cards.pop();
+    }
 }
 
 #[cfg(test)]
@@ -201,4 +297,41 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_generate() {
+        let generated = DealerHandPatterns::generate(false);
+        assert_eq!(&generated, DealerHandPatterns::standard());
+
+        // 保存して読み直しても、組み込みのテーブルと同じ確率になる
+        let loaded = DealerHandPatterns::from_bytes(&generated.to_bytes().unwrap()).unwrap();
+        let deck = Deck::new(1);
+        assert_eq!(
+            deck.calc_dealer_hand_prob_with_patterns(&loaded).unwrap(),
+            deck.calc_dealer_hand_prob_with_patterns(DealerHandPatterns::standard())
+                .unwrap()
+        );
+
+        // ソフト17で引くと、6 のアップカードから A を引いた並びはソフト17で止まらない
+        let h17 = DealerHandPatterns::generate(true);
+        let soft_17 = Deck::zero().add(Card::Ace);
+        assert_eq!(
+            DealerHandPatterns::standard().n6.value_17.get(&soft_17),
+            Some(&1)
+        );
+        assert_eq!(h17.n6.value_17.get(&soft_17), None);
+        assert_ne!(
+            h17.fingerprint(),
+            DealerHandPatterns::standard().fingerprint()
+        );
+        let h17_probs = deck.calc_dealer_hand_prob_with_patterns(&h17).unwrap();
+        assert_eq!(
+            h17_probs
+                .n6
+                .iter()
+                .map(|(_, p)| *p)
+                .sum::<num::rational::Ratio<u128>>(),
+            num::rational::Ratio::new(1, 1)
+        );
+    }
 }
@@ -1,1380 +1,1381 @@
 use crate::models::{DealerHandDeckMap, Deck};
 use maplit::btreemap;
-use std::sync::LazyLock;
 
-// This is an example static instance for the ace card patterns
-pub(crate) static ACE_PATTERNS: LazyLock<DealerHandDeckMap> = LazyLock::new(|| DealerHandDeckMap {
-    value_17: btreemap! {
-        Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 20,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 28,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 20,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 18,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 24,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 33,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 18,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 2, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 9,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 20,
-        Deck { ace: 4, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 24,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 34,
-        Deck { ace: 1, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 18,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 8,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 2, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 30,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 38,
-        Deck { ace: 7, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 18,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 36,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 10, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
-        Deck { ace: 4, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 9,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 32,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 26,
-        Deck { ace: 5, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 28,
-        Deck { ace: 8, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 6, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 22,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 8,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
-    },
-    value_18: btreemap! {
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 18,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 5, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 18,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 7, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 4, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 22,
-        Deck { ace: 5, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 1, n2: 2, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 17,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 9, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 11,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 18,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 5, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 2, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 3, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 38,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 6, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 8, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 4, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 18,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 8,
-        Deck { ace: 2, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 19,
-        Deck { ace: 0, n2: 1, n3: 3, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 0, n2: 2, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 4, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 30,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 5, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 7, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 17,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 1, n2: 5, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 8, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 3, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 30,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 21,
-        Deck { ace: 6, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-    },
-    value_19: btreemap! {
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 4, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 5, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 4, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 19,
-        Deck { ace: 4, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 1, n3: 3, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 9,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 2, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 15,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 8, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 13,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 11,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 19,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 6, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 0, n2: 2, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 22,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 2, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 9, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 1, n2: 1, n3: 3, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 7, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 8,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 5, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
-        Deck { ace: 3, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
-        Deck { ace: 3, n2: 0, n3: 3, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 3, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 11,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
-        Deck { ace: 0, n2: 4, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 24,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 8,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 5, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 1, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 6, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 11,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
-        Deck { ace: 7, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-    },
-    value_20: btreemap! {
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 3, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 24,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 19,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
-        Deck { ace: 9, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 15,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 3, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 8,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 13,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 0, n2: 4, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 8,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 9,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 19,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 11,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 8,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-    },
-    value_21: btreemap! {
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 5,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 11,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 9,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 24,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 16,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 17,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 21,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 15,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 17,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 17,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 11,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 9,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 5,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 19,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 15,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 19,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-    },
-    blackjack: btreemap! {
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-    },
-    burst: btreemap! {
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 7,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 11,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 4,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 21,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 4,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 4,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 4,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 8,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 15,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 25,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 35,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 8,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 21,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 13,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 19,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 7,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 28,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 11,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 8,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 22,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 8,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 15,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 29,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 31,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 8,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 3,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 3,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 7,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 12,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 24,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 7,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 13,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 8,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 19,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 12,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 14,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 24,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 13,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 4,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 9,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 16,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 11,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 7,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 18,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 16,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 18,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 11,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 19,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 10,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 16,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 9,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 5,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 16,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 16,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 9,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 13,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 4,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 7,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 8,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 9,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 6,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 33,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 11,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 2,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 5,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 15,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 4,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 7,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 4,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 24,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 26,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 14,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 8,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 23,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 3,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 20,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 10,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 11,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 4,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 22,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 26,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 11,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 10,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 28,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 22,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 20,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 6,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 9,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 10,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 2,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 20,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 3,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 16,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 36,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 4,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 13,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 7,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 11,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 19,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 12,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 22,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 22,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 4,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 14,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 15,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 7,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 4,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 18,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 4,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 7,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 21,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 10,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 16,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 8,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 3,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 5,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 7,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 15,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 6,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 10,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 7,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 20,
-        Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 8,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 15,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 4,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 14,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 7,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 19,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 12,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 3,
-        Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 18,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 3,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 7,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 6,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 8,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 6,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 9,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 15,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 13,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 24,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 13,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 18,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 10,
-        Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 18,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 15,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 24,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
-        Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 15,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 15,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 23,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 19,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 14,
-        Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 9,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 14,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 14,
-        Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 7,
-        Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 20,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 7,
-        Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 14,
-        Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
-        Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 3,
-        Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
-        Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 7,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
-        Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
-        Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 2,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 15,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 6,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
-        Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 16,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 17,
-        Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
-        Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 19,
-        Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 11,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 19,
-        Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 24,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
-        Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
-        Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 12,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 4,
-        Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
-        Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
-        Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 9,
-        Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 10,
-        Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
-        Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
-        Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 6,
-        Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 12,
-        Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
-        Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
-        Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
-        Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 10,
-        Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 6,
-        Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
-        Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 9,
-    },
-});
+// 組み込みのテーブル (ソフト17スタンド). DealerHandPatterns::standard() が一度だけ作る
+pub(super) fn ace_patterns() -> DealerHandDeckMap {
+    DealerHandDeckMap {
+        value_17: btreemap! {
+            Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 20,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 28,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 20,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 18,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 24,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 33,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 18,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 2, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 9,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 20,
+            Deck { ace: 4, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 24,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 34,
+            Deck { ace: 1, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 18,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 8,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 2, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 30,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 38,
+            Deck { ace: 7, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 18,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 36,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 10, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
+            Deck { ace: 4, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 9,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 32,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 26,
+            Deck { ace: 5, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 28,
+            Deck { ace: 8, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 6, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 22,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 8,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
+        },
+        value_18: btreemap! {
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 18,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 5, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 18,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 7, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 4, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 22,
+            Deck { ace: 5, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 1, n2: 2, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 17,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 9, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 11,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 18,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 5, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 2, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 3, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 38,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 6, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 8, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 4, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 18,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 8,
+            Deck { ace: 2, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 19,
+            Deck { ace: 0, n2: 1, n3: 3, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 0, n2: 2, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 4, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 30,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 5, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 7, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 17,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 1, n2: 5, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 8, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 3, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 30,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 21,
+            Deck { ace: 6, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+        },
+        value_19: btreemap! {
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 4, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 5, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 4, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 19,
+            Deck { ace: 4, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 1, n3: 3, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 9,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 2, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 15,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 8, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 13,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 11,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 19,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 6, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 0, n2: 2, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 22,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 2, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 9, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 1, n2: 1, n3: 3, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 7, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 8,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 5, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
+            Deck { ace: 3, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
+            Deck { ace: 3, n2: 0, n3: 3, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 3, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 11,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
+            Deck { ace: 0, n2: 4, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 24,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 8,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 5, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 1, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 6, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 11,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
+            Deck { ace: 7, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 3, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+        },
+        value_20: btreemap! {
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 3, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 24,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 19,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
+            Deck { ace: 9, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 15,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 3, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 8,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 13,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 0, n2: 4, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 8,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 9,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 19,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 11,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 8,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+        },
+        value_21: btreemap! {
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 14,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 5,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 11,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 9,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 24,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 16,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 17,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 21,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 15,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 9,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 17,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 17,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 2, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 11,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 9,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 9,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 5,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 2, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 2, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 19,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 15,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 22,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 21,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 19,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+        },
+        blackjack: btreemap! {
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+        },
+        burst: btreemap! {
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 7,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 11,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 4,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 21,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 4,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 4,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 4,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 8,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 15,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 16,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 25,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 35,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 8,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 21,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 13,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 19,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 7,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 28,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 11,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 8,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 22,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 8,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 15,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 29,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 31,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 8,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 3,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 3,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 7,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 12,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 24,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 7,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 13,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 8,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 19,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 12,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 14,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 24,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 13,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 4,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 9,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 16,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 11,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 7,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 18,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 16,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 18,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 11,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 19,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 10,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 16,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 9,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 5,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 16,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 16,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 9,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 13,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 4,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 7,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 7,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 8,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 9,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 6,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 14,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 33,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 11,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 2,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 5,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 15,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 4,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 7,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 16,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 4,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 24,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 26,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 14,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 8,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 23,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 12,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 3,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 20,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 10,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 11,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 4,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 22,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 26,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 11,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 10,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 28,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 22,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 20,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 6,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 9,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 10,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 2,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 20,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 3,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 16,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 36,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 4,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 13,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 7,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 11,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 19,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 7,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 12,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 22,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 22,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 4,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 14,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 15,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 7,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 4,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 18,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 4,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 7,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 21,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 10,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 5, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 16,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 7,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 2,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 8,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 3,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 5,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 7,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 15,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 6,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 10,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 7,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 20,
+            Deck { ace: 6, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 5, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 8,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 15,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 4,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 5,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 14,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 7,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 19,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 12,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 3,
+            Deck { ace: 7, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 18,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 3,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 7,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 6,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 8,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 3,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 6,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 0, n5: 1, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 9,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 15,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 13,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 24,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 13,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 18,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 10,
+            Deck { ace: 1, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 18,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 15,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 24,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 2,
+            Deck { ace: 2, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 15,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 7, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 15,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 2, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 23,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 15,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 2, n2: 1, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 1, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 4,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 4, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 19,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 1, face: 0 } => 14,
+            Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 9,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 14,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 14,
+            Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 7,
+            Deck { ace: 4, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 20,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 3,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 5, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 7,
+            Deck { ace: 0, n2: 1, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 4,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 2, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 14,
+            Deck { ace: 2, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 3,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 5, n2: 1, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 4, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 3, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 7,
+            Deck { ace: 8, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 2,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 3,
+            Deck { ace: 3, n2: 3, n3: 0, n4: 0, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 13,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 3,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 2,
+            Deck { ace: 6, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 7,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 6,
+            Deck { ace: 3, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 6,
+            Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 2,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 15,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 1,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 2 } => 4,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 3,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 6,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 3,
+            Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 16,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 17,
+            Deck { ace: 0, n2: 3, n3: 1, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 2,
+            Deck { ace: 9, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 19,
+            Deck { ace: 0, n2: 1, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 4, n2: 2, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 11,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 2, n9: 0, face: 0 } => 19,
+            Deck { ace: 0, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 24,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 1, n5: 1, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 1,
+            Deck { ace: 2, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 5,
+            Deck { ace: 3, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 12,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 1, face: 1 } => 4,
+            Deck { ace: 2, n2: 2, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 15,
+            Deck { ace: 1, n2: 2, n3: 0, n4: 1, n5: 0, n6: 2, n7: 0, n8: 0, n9: 0, face: 0 } => 5,
+            Deck { ace: 3, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 9,
+            Deck { ace: 3, n2: 1, n3: 0, n4: 1, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 10,
+            Deck { ace: 3, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 2, n8: 0, n9: 0, face: 0 } => 1,
+            Deck { ace: 0, n2: 1, n3: 1, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 0, face: 1 } => 6,
+            Deck { ace: 0, n2: 2, n3: 0, n4: 0, n5: 1, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 1, n2: 1, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 6,
+            Deck { ace: 2, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 1 } => 12,
+            Deck { ace: 0, n2: 0, n3: 0, n4: 2, n5: 0, n6: 0, n7: 1, n8: 0, n9: 1, face: 0 } => 1,
+            Deck { ace: 2, n2: 0, n3: 0, n4: 0, n5: 1, n6: 0, n7: 1, n8: 1, n9: 0, face: 0 } => 2,
+            Deck { ace: 1, n2: 0, n3: 2, n4: 0, n5: 0, n6: 1, n7: 0, n8: 1, n9: 0, face: 0 } => 4,
+            Deck { ace: 1, n2: 1, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 2, face: 0 } => 10,
+            Deck { ace: 4, n2: 0, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 6,
+            Deck { ace: 6, n2: 0, n3: 0, n4: 0, n5: 0, n6: 1, n7: 0, n8: 0, n9: 1, face: 0 } => 5,
+            Deck { ace: 4, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 0, n8: 1, n9: 0, face: 1 } => 9,
+        },
+    }
+}
@@ -0,0 +1,261 @@
+use super::{DealerHandDeckMap, DealerHandPatterns};
+use crate::models::{Card, Deck, HandTotal};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+// フォーマット
+// magic "BJDP" | version: u16 | (アップカード10種 x 合計7種) の map | crc32: u32
+// map: entries: u32 | (deck: u8 x 10, count: u32) x entries
+// 数値はすべてリトルエンディアン
+const MAGIC: &[u8; 4] = b"BJDP";
+pub const DEALER_PATTERN_FORMAT_VERSION: u16 = 1;
+
+const TOTALS: [HandTotal; 7] = [
+    HandTotal::Value(17),
+    HandTotal::Value(18),
+    HandTotal::Value(19),
+    HandTotal::Value(20),
+    HandTotal::Value(21),
+    HandTotal::Burst,
+    HandTotal::BlackJack,
+];
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.to_string())
+}
+
+// フォーマットに収まらないテーブル. 読み込みで壊れたデータを返さないよう、書くときに弾く
+fn too_large(message: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidInput, message)
+}
+
+impl DealerHandDeckMap {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) -> io::Result<()> {
+        for total in TOTALS.iter() {
+            let patterns = self.get(total);
+            let entries = u32::try_from(patterns.len())
+                .map_err(|_| too_large(format!("too many patterns: {}", patterns.len())))?;
+            bytes.extend_from_slice(&entries.to_le_bytes());
+            for (deck, count) in patterns.iter() {
+                // 組み込みのテーブルはディーラーが21を超えたら止まるので 255 枚には届かない
+                for card_count in deck.to_array() {
+                    let card_count = u8::try_from(card_count).map_err(|_| {
+                        too_large(format!("too many cards of one rank in {:?}", deck))
+                    })?;
+                    bytes.push(card_count);
+                }
+                let count = u32::try_from(*count)
+                    .map_err(|_| too_large(format!("pattern count {} of {:?}", count, deck)))?;
+                bytes.extend_from_slice(&count.to_le_bytes());
+            }
+        }
+        Ok(())
+    }
+
+    fn read_bytes(reader: &mut Reader) -> io::Result<Self> {
+        let mut dealer_hand_deck_map = DealerHandDeckMap::empty();
+        for total in TOTALS.iter() {
+            let entries = reader.u32()? as usize;
+            let mut patterns = BTreeMap::new();
+            for _ in 0..entries {
+                let mut deck = Deck::zero();
+                for card in Card::ALL {
+                    for _ in 0..reader.u8()? {
+                        deck.add_mut(card);
+                    }
+                }
+                patterns.insert(deck, reader.u32()? as usize);
+            }
+            *dealer_hand_deck_map.get_mut(total) = patterns;
+        }
+        Ok(dealer_hand_deck_map)
+    }
+}
+
+impl DealerHandPatterns {
+    // u8 / u32 に収まらない枚数やパターン数があれば ErrorKind::InvalidInput
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&DEALER_PATTERN_FORMAT_VERSION.to_le_bytes());
+        for card in Card::ALL {
+            self.get_map(card).write_bytes(&mut bytes)?;
+        }
+        let checksum = crc32fast::hash(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        Ok(bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() < MAGIC.len() + 2 + 4 {
+            return Err(invalid_data("dealer pattern file is too short"));
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - 4);
+        if crc32fast::hash(body).to_le_bytes() != checksum {
+            return Err(invalid_data("dealer pattern checksum mismatch"));
+        }
+
+        let mut reader = Reader {
+            bytes: body,
+            pos: 0,
+        };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(invalid_data("not a dealer pattern file"));
+        }
+        let version = reader.u16()?;
+        if version != DEALER_PATTERN_FORMAT_VERSION {
+            return Err(invalid_data(&format!(
+                "unsupported dealer pattern format version {}",
+                version
+            )));
+        }
+
+        let mut dealer_hand_patterns = DealerHandPatterns::empty();
+        for card in Card::ALL {
+            *dealer_hand_patterns.get_map_mut(card) = DealerHandDeckMap::read_bytes(&mut reader)?;
+        }
+        if reader.pos != body.len() {
+            return Err(invalid_data("trailing bytes in dealer pattern file"));
+        }
+        Ok(dealer_hand_patterns)
+    }
+
+    // テーブルの中身の crc32. 別のテーブルで計算したキャッシュを混ぜないために使う
+    pub fn fingerprint(&self) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+        for card in Card::ALL {
+            let map = self.get_map(card);
+            for total in TOTALS.iter() {
+                for (deck, count) in map.get(total).iter() {
+                    for card_count in deck.to_array() {
+                        hasher.update(&(card_count as u64).to_le_bytes());
+                    }
+                    hasher.update(&(*count as u64).to_le_bytes());
+                }
+                // 合計の区切り
+                hasher.update(&[0xff]);
+            }
+        }
+        hasher.finalize()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_bytes()?)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        let end = self.pos + len;
+        if end > self.bytes.len() {
+            return Err(invalid_data("dealer pattern file is truncated"));
+        }
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> io::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::rational::Ratio;
+
+    #[test]
+    fn test_round_trip() {
+        let patterns = DealerHandPatterns::standard();
+        let bytes = patterns.to_bytes().unwrap();
+        let loaded = DealerHandPatterns::from_bytes(&bytes).unwrap();
+
+        assert_eq!(&loaded, patterns);
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let mut bytes = DealerHandPatterns::standard().to_bytes().unwrap();
+        bytes[10] ^= 1;
+
+        let err = DealerHandPatterns::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let mut bytes = DealerHandPatterns::standard().to_bytes().unwrap();
+        bytes.truncate(bytes.len() - 4);
+        bytes[4..6].copy_from_slice(&(DEALER_PATTERN_FORMAT_VERSION + 1).to_le_bytes());
+        let checksum = crc32fast::hash(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+
+        let err = DealerHandPatterns::from_bytes(&bytes).unwrap_err();
+        assert!(err.to_string().contains("version"), "{}", err);
+    }
+
+    #[test]
+    fn test_too_large() {
+        let mut patterns = DealerHandPatterns::empty();
+        let mut deck = Deck::zero();
+        for _ in 0..256 {
+            deck.add_mut(Card::N2);
+        }
+        patterns.n2.burst.insert(deck, 1);
+
+        let err = patterns.to_bytes().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        let mut patterns = DealerHandPatterns::empty();
+        patterns.n2.burst.insert(
+            Deck::new_from_strs(&vec!["10", "10"]),
+            u32::MAX as usize + 1,
+        );
+        let err = patterns.to_bytes().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_save_load() {
+        let path =
+            std::env::temp_dir().join(format!("bjc_dealer_patterns_{}.bin", std::process::id()));
+        DealerHandPatterns::standard().save(&path).unwrap();
+        let loaded = DealerHandPatterns::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let deck = Deck::new(1);
+        assert_eq!(
+            deck.calc_dealer_hand_prob_with_patterns(&loaded).unwrap(),
+            *deck.dealer_probs().unwrap()
+        );
+        assert_eq!(
+            deck.calc_dealer_hand_prob_with_patterns(&loaded)
+                .unwrap()
+                .ace
+                .iter()
+                .map(|(_, p)| *p)
+                .sum::<Ratio<u128>>(),
+            Ratio::new(1, 1)
+        );
+    }
+}
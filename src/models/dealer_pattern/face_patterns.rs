@@ -1,10 +1,9 @@
 use crate::models::{DealerHandDeckMap, Deck};
 use maplit::btreemap;
-use std::sync::LazyLock;
 
-// This is an example static instance for the ace card patterns
-pub(crate) static FACE_PATTERNS: LazyLock<DealerHandDeckMap> =
-    LazyLock::new(|| DealerHandDeckMap {
+// 組み込みのテーブル (ソフト17スタンド). DealerHandPatterns::standard() が一度だけ作る
+pub(super) fn face_patterns() -> DealerHandDeckMap {
+    DealerHandDeckMap {
         value_17: btreemap! {
             Deck { ace: 0, n2: 0, n3: 1, n4: 1, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 2,
             Deck { ace: 1, n2: 3, n3: 0, n4: 0, n5: 0, n6: 0, n7: 0, n8: 0, n9: 0, face: 0 } => 3,
@@ -203,4 +202,5 @@ pub(crate) static FACE_PATTERNS: LazyLock<DealerHandDeckMap> =
             Deck { ace: 1, n2: 0, n3: 0, n4: 1, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
             Deck { ace: 2, n2: 0, n3: 1, n4: 0, n5: 0, n6: 0, n7: 1, n8: 0, n9: 0, face: 0 } => 1,
         },
-    });
+    }
+}
@@ -1,15 +1,17 @@
-use crate::models::{Card, Cards, DealerHandDeckMap, HandTotal};
+use crate::cache::{BoundedCache, DealerProbsCache};
+use crate::models::{Card, Cards, DealerHandPatterns, DealerProbEngine, HandTotal};
+use crate::Error;
 
 use num::rational::Ratio;
+use num::{BigInt, CheckedAdd, CheckedDiv, CheckedMul, One, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
-use std::collections::BTreeMap;
-use std::sync::{Arc, LazyLock, RwLock};
+use std::sync::{Arc, LazyLock};
 use std::vec;
 
 // dealerのdeckごとの 16,
-static DECK_DEALER_PROBS_REPOSITORY: LazyLock<RwLock<BTreeMap<Deck, Arc<DealerHandProb>>>> =
-    LazyLock::new(|| RwLock::new(BTreeMap::new()));
+static DECK_DEALER_PROBS_REPOSITORY: LazyLock<Arc<DealerProbsCache>> =
+    LazyLock::new(|| Arc::new(BoundedCache::new()));
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
 pub struct Deck {
@@ -115,31 +117,69 @@ impl Deck {
         card_counts
     }
 
-    pub(crate) fn clear_cache() {
-        let mut deck_dealer_probs = DECK_DEALER_PROBS_REPOSITORY.write().unwrap();
-        deck_dealer_probs.clear();
+    // デフォルトの Solver と共有する
+    pub(crate) fn default_dealer_probs_cache() -> Arc<DealerProbsCache> {
+        Arc::clone(&DECK_DEALER_PROBS_REPOSITORY)
     }
 
-    pub(crate) fn dealer_probs(&self) -> Arc<DealerHandProb> {
-        {
-            let deck_dealer_probs = DECK_DEALER_PROBS_REPOSITORY.read().unwrap();
-            if let Some(dealer_probs) = deck_dealer_probs.get(&self) {
-                return Arc::clone(dealer_probs);
-            }
+    // アップカードごとのディーラーの最終合計の分布. selfはアップカードを除いた残りのdeck
+    // 確率が Ratio<u128> に収まらないほど大きいdeckは Error::Overflow
+    pub fn dealer_probs(&self) -> Result<Arc<DealerHandProb>, Error> {
+        if let Some(dealer_probs) = DECK_DEALER_PROBS_REPOSITORY.get(self) {
+            return Ok(dealer_probs);
         }
 
-        let dealer_probs = Arc::new(self.calc_dealer_hand_prob());
-        let key_clone = self.clone();
+        let dealer_probs = Arc::new(self.calc_dealer_hand_prob()?);
+        DECK_DEALER_PROBS_REPOSITORY.insert(self.clone(), Arc::clone(&dealer_probs));
+
+        Ok(dealer_probs)
+    }
+
+    // アップカードがup_cardのときの分布. selfはアップカードを除いた残りのdeck
+    pub fn dealer_total_prob(&self, up_card: Card) -> Result<DealerTotalProb, Error> {
+        Ok(self.dealer_probs()?.get(up_card).clone())
+    }
 
-        {
-            let mut deck_dealer_probs = DECK_DEALER_PROBS_REPOSITORY.write().unwrap();
-            deck_dealer_probs.insert(key_clone, Arc::clone(&dealer_probs));
+    // アップカードが配られる前の分布. selfからアップカードを引くところから計算する
+    pub fn dealer_total_prob_before_up_card(&self) -> Result<DealerTotalProb, Error> {
+        let mut dealer_total_prob = DealerTotalProb::new();
+        for up_card in self.remaining_ranks() {
+            let prob = self.draw_probability(up_card);
+            let prob = Ratio::new(*prob.numer() as u128, *prob.denom() as u128);
+            let probs = self.remove(up_card).dealer_probs()?;
+            dealer_total_prob.add_all_scaled(probs.get(up_card), &prob)?;
         }
+        Ok(dealer_total_prob)
+    }
+
+    // up_card のディーラーがBJでないと分かっているときの分布
+    pub fn dealer_probs_no_blackjack(&self, up_card: Card) -> Result<DealerTotalProb, Error> {
+        self.dealer_probs()?.get(up_card).given_no_blackjack()
+    }
 
-        dealer_probs
+    pub(crate) fn calc_dealer_hand_prob(&self) -> Result<DealerHandProb, Error> {
+        self.calc_dealer_hand_prob_with(DealerProbEngine::default())
     }
 
-    pub(crate) fn calc_dealer_hand_prob(&self) -> DealerHandProb {
+    pub fn calc_dealer_hand_prob_with(
+        &self,
+        engine: DealerProbEngine,
+    ) -> Result<DealerHandProb, Error> {
+        match engine {
+            DealerProbEngine::Pattern => self.calc_dealer_hand_prob_by_patterns(),
+            DealerProbEngine::Recursive => self.calc_dealer_hand_prob_recursive(),
+        }
+    }
+
+    fn calc_dealer_hand_prob_by_patterns(&self) -> Result<DealerHandProb, Error> {
+        self.calc_dealer_hand_prob_with_patterns(DealerHandPatterns::standard())
+    }
+
+    // 読み込んだ別のルールのテーブルでも計算できるようにする. 結果はキャッシュしない
+    pub fn calc_dealer_hand_prob_with_patterns(
+        &self,
+        dealer_hand_patterns: &DealerHandPatterns,
+    ) -> Result<DealerHandProb, Error> {
         // key: card, value: total_value_prob
         let mut dealer_hand_prob = DealerHandProb::new();
 
@@ -169,22 +209,21 @@ impl Deck {
                 HandTotal::Burst,
             ];
             for sum_number in card_values.iter() {
-                let patterns = DealerHandDeckMap::find(card, sum_number);
+                let patterns = dealer_hand_patterns.get(card, sum_number);
                 // let patterns = dealer_pattern.patterns;
-                let prob = patterns
-                    .iter()
-                    .map(|(pattern, count)| {
-                        let prob = self.deck_draw_probability(&pattern);
-                        prob * *count as u128
-                    })
-                    .sum::<Ratio<u128>>();
+                let mut prob = Ratio::new(0, 1);
+                for (pattern, count) in patterns.iter() {
+                    let pattern_prob = self.deck_draw_probability(pattern)?;
+                    let pattern_prob = checked_mul(&pattern_prob, &Ratio::from(*count as u128))?;
+                    prob = checked_add(&prob, &pattern_prob)?;
+                }
 
                 dealer_total_prob.insert(sum_number, prob);
             }
 
             dealer_hand_prob.insert(card, dealer_total_prob);
         }
-        dealer_hand_prob
+        Ok(dealer_hand_prob)
     }
 
     // 1つ以上のカードが残っているカードの種類を返す
@@ -240,40 +279,84 @@ impl Deck {
         }
     }
 
-    pub fn remove_mut(&mut self, card: Card) {
+    pub fn try_add_mut(&mut self, card: Card) -> Result<(), Error> {
+        let count = self.count_mut(card);
+        *count = count
+            .checked_add(1)
+            .ok_or_else(|| Error::InvalidDeck(format!("too many {:?}", card)))?;
+        Ok(())
+    }
+
+    pub fn try_add(&self, card: Card) -> Result<Self, Error> {
+        let mut new_cards = self.clone();
+        new_cards.try_add_mut(card)?;
+        Ok(new_cards)
+    }
+
+    // 無いカードを引こうとしたら InvalidDeck. その場合 self は変わらない
+    pub fn try_remove_mut(&mut self, card: Card) -> Result<(), Error> {
+        let count = self.count_mut(card);
+        *count = count
+            .checked_sub(1)
+            .ok_or_else(|| Error::InvalidDeck(format!("no {:?} left in the deck", card)))?;
+        Ok(())
+    }
+
+    pub fn try_remove(&self, card: Card) -> Result<Self, Error> {
+        let mut new_cards = self.clone();
+        new_cards.try_remove_mut(card)?;
+        Ok(new_cards)
+    }
+
+    pub fn try_remove_deck(&self, deck: &Deck) -> Result<Self, Error> {
+        let mut new_deck = self.clone();
+        for card in Card::ALL {
+            let count = new_deck.count_mut(card);
+            *count = count.checked_sub(deck.count(card)).ok_or_else(|| {
+                Error::InvalidDeck(format!(
+                    "cannot remove {} {:?} from a deck with {}",
+                    deck.count(card),
+                    card,
+                    self.count(card)
+                ))
+            })?;
+        }
+        Ok(new_deck)
+    }
+
+    // 不明な文字列があれば InvalidDeck. new_from_strs は読み飛ばす
+    pub fn try_new_from_strs(strs: &Vec<&str>) -> Result<Self, Error> {
+        let mut card_counts = Deck::zero();
+        for s in strs {
+            let card = Card::from_str(s)
+                .ok_or_else(|| Error::InvalidDeck(format!("unknown card {:?}", s)))?;
+            card_counts.try_add_mut(card)?;
+        }
+        Ok(card_counts)
+    }
+
+    fn count_mut(&mut self, card: Card) -> &mut usize {
         match card {
-            Card::Ace => {
-                self.ace -= 1;
-            }
-            Card::N2 => {
-                self.n2 -= 1;
-            }
-            Card::N3 => {
-                self.n3 -= 1;
-            }
-            Card::N4 => {
-                self.n4 -= 1;
-            }
-            Card::N5 => {
-                self.n5 -= 1;
-            }
-            Card::N6 => {
-                self.n6 -= 1;
-            }
-            Card::N7 => {
-                self.n7 -= 1;
-            }
-            Card::N8 => {
-                self.n8 -= 1;
-            }
-            Card::N9 => {
-                self.n9 -= 1;
-            }
-            Card::Face => {
-                self.face -= 1;
-            }
+            Card::Ace => &mut self.ace,
+            Card::N2 => &mut self.n2,
+            Card::N3 => &mut self.n3,
+            Card::N4 => &mut self.n4,
+            Card::N5 => &mut self.n5,
+            Card::N6 => &mut self.n6,
+            Card::N7 => &mut self.n7,
+            Card::N8 => &mut self.n8,
+            Card::N9 => &mut self.n9,
+            Card::Face => &mut self.face,
+        }
+    }
+
+    // 無いカードは panic. 入力由来のデッキには try_remove_mut を使う
+    pub fn remove_mut(&mut self, card: Card) {
+        if let Err(e) = self.try_remove_mut(card) {
+            panic!("{}", e);
         }
     }
+
     pub fn add(&self, card: Card) -> Self {
         let mut new_cards = self.clone();
         new_cards.add_mut(card);
@@ -287,16 +370,10 @@ impl Deck {
     }
 
     pub fn remove_mut_deck(&mut self, deck: &Deck) {
-        self.ace -= deck.ace;
-        self.n2 -= deck.n2;
-        self.n3 -= deck.n3;
-        self.n4 -= deck.n4;
-        self.n5 -= deck.n5;
-        self.n6 -= deck.n6;
-        self.n7 -= deck.n7;
-        self.n8 -= deck.n8;
-        self.n9 -= deck.n9;
-        self.face -= deck.face;
+        match self.try_remove_deck(deck) {
+            Ok(new_deck) => *self = new_deck,
+            Err(e) => panic!("{}", e),
+        }
     }
 
     pub fn remove_deck(&self, deck: &Deck) -> Self {
@@ -340,32 +417,44 @@ impl Deck {
         Ratio::new(target_count, total_cards)
     }
 
-    pub(crate) fn deck_draw_probability(&self, draw_deck: &Deck) -> Ratio<u128> {
-        let mut total_cards_count = self.total_cards() as u128;
+    // 約分しても Ratio<u128> に収まらなければ Error::Overflow
+    pub(crate) fn deck_draw_probability(&self, draw_deck: &Deck) -> Result<Ratio<u128>, Error> {
+        let Some(factors) = self.draw_factors(draw_deck) else {
+            return Ok(Ratio::new(0, 1)); // Not enough cards, return zero probability
+        };
+
+        // まずは u128 で計算し、途中で溢れたら BigInt で計算し直す
+        if let Some(prob) = draw_probability_checked(&factors) {
+            return Ok(prob);
+        }
+
+        from_big(draw_probability_big(&factors)).ok_or_else(|| {
+            Error::Overflow(format!(
+                "draw probability of {:?} from {:?} does not fit in u128",
+                draw_deck, self
+            ))
+        })
+    }
+
+    // draw_deck を順に引くときの (残りのそのカードの枚数, 残りの全体の枚数) の列
+    // 足りないカードがあれば None
+    fn draw_factors(&self, draw_deck: &Deck) -> Option<SmallVec<[(usize, usize); 16]>> {
+        let mut total_cards_count = self.total_cards();
         let self_array = self.to_array();
-        let mut return_prob = Ratio::new(1, 1);
+        let mut factors = SmallVec::new();
 
         for (i, &draw_count) in draw_deck.to_array().iter().enumerate() {
-            if draw_count == 0 {
-                continue;
-            }
-
             if self_array[i] < draw_count {
-                return Ratio::new(0, 1); // Not enough cards, return zero probability
+                return None;
             }
 
-            let available_count = self_array[i] as u128;
-
-            // Directly calculate the product of probabilities for each card draw
-
-            ((available_count - draw_count as u128 + 1)..=available_count).for_each(|c| {
-                let prob = Ratio::new(c, total_cards_count);
+            let available_count = self_array[i];
+            for c in (available_count - draw_count + 1)..=available_count {
+                factors.push((c, total_cards_count));
                 total_cards_count -= 1;
-                return_prob *= prob;
-            })
+            }
         }
-        return_prob
-        // Multiply all the individual probabilities together
+        Some(factors)
     }
 
     pub(crate) fn to_array(&self) -> [usize; 10] {
@@ -376,19 +465,86 @@ impl Deck {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+// (引くカードの残り枚数, 全体の残り枚数) の積. 約分前の積が u128 に収まらない場合は None
+fn draw_probability_checked(factors: &[(usize, usize)]) -> Option<Ratio<u128>> {
+    let mut numer: u128 = 1;
+    let mut denom: u128 = 1;
+
+    for &(c, total) in factors {
+        numer = numer.checked_mul(c as u128)?;
+        denom = denom.checked_mul(total as u128)?;
+    }
+    Some(Ratio::new(numer, denom))
+}
+
+fn draw_probability_big(factors: &[(usize, usize)]) -> Ratio<BigInt> {
+    let mut numer = BigInt::one();
+    let mut denom = BigInt::one();
+
+    for &(c, total) in factors {
+        numer *= c;
+        denom *= total;
+    }
+    Ratio::new(numer, denom)
+}
+
+fn to_big(value: &Ratio<u128>) -> Ratio<BigInt> {
+    Ratio::new_raw(BigInt::from(*value.numer()), BigInt::from(*value.denom()))
+}
+
+// 約分済みの値が u128 に収まらなければ None
+fn from_big(value: Ratio<BigInt>) -> Option<Ratio<u128>> {
+    Some(Ratio::new_raw(
+        value.numer().to_u128()?,
+        value.denom().to_u128()?,
+    ))
+}
+
+// u128 の途中計算が溢れたら BigInt で計算し直す. 結果が収まらなければ Error::Overflow
+fn checked_op(
+    a: &Ratio<u128>,
+    b: &Ratio<u128>,
+    name: &str,
+    checked: impl Fn(&Ratio<u128>, &Ratio<u128>) -> Option<Ratio<u128>>,
+    big: impl Fn(Ratio<BigInt>, Ratio<BigInt>) -> Ratio<BigInt>,
+) -> Result<Ratio<u128>, Error> {
+    checked(a, b)
+        .or_else(|| from_big(big(to_big(a), to_big(b))))
+        .ok_or_else(|| Error::Overflow(format!("{} {} {} does not fit in u128", a, name, b)))
+}
+
+pub(crate) fn checked_add(a: &Ratio<u128>, b: &Ratio<u128>) -> Result<Ratio<u128>, Error> {
+    checked_op(a, b, "+", |a, b| a.checked_add(b), |a, b| a + b)
+}
+
+pub(crate) fn checked_mul(a: &Ratio<u128>, b: &Ratio<u128>) -> Result<Ratio<u128>, Error> {
+    checked_op(a, b, "*", |a, b| a.checked_mul(b), |a, b| a * b)
+}
+
+fn checked_div(a: &Ratio<u128>, b: &Ratio<u128>) -> Result<Ratio<u128>, Error> {
+    checked_op(a, b, "/", |a, b| a.checked_div(b), |a, b| a / b)
+}
+
+// ディーラーの最終合計ごとの確率
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct DealerTotalProb {
-    s17: Ratio<u128>,
-    s18: Ratio<u128>,
-    s19: Ratio<u128>,
-    s20: Ratio<u128>,
-    s21: Ratio<u128>,
-    black_jack: Ratio<u128>,
-    bust: Ratio<u128>,
+    pub s17: Ratio<u128>,
+    pub s18: Ratio<u128>,
+    pub s19: Ratio<u128>,
+    pub s20: Ratio<u128>,
+    pub s21: Ratio<u128>,
+    pub black_jack: Ratio<u128>,
+    pub bust: Ratio<u128>,
+}
+
+impl Default for DealerTotalProb {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DealerTotalProb {
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         DealerTotalProb {
             s17: Ratio::new(0, 1),
             s18: Ratio::new(0, 1),
@@ -413,7 +569,73 @@ impl DealerTotalProb {
         }
     }
 
-    pub(crate) fn iter(&self) -> vec::IntoIter<(&HandTotal, &Ratio<u128>)> {
+    pub(crate) fn add_scaled(
+        &mut self,
+        total: &HandTotal,
+        prob: &Ratio<u128>,
+    ) -> Result<(), Error> {
+        let entry = match total {
+            HandTotal::Value(17) => &mut self.s17,
+            HandTotal::Value(18) => &mut self.s18,
+            HandTotal::Value(19) => &mut self.s19,
+            HandTotal::Value(20) => &mut self.s20,
+            HandTotal::Value(21) => &mut self.s21,
+            HandTotal::BlackJack => &mut self.black_jack,
+            HandTotal::Burst => &mut self.bust,
+            _ => panic!("Invalid total"),
+        };
+        *entry = checked_add(entry, prob)?;
+        Ok(())
+    }
+
+    // ディーラーが止まらない合計 (16以下) は None
+    pub fn get(&self, total: &HandTotal) -> Option<&Ratio<u128>> {
+        match total {
+            HandTotal::Value(17) => Some(&self.s17),
+            HandTotal::Value(18) => Some(&self.s18),
+            HandTotal::Value(19) => Some(&self.s19),
+            HandTotal::Value(20) => Some(&self.s20),
+            HandTotal::Value(21) => Some(&self.s21),
+            HandTotal::BlackJack => Some(&self.black_jack),
+            HandTotal::Burst => Some(&self.bust),
+            _ => None,
+        }
+    }
+
+    // ディーラーがBJでないという条件付きの分布. BJ以外を 1 - P(BJ) で割る
+    // BJしかありえない場合は、条件が成り立たないので全て0を返す
+    pub fn given_no_blackjack(&self) -> Result<DealerTotalProb, Error> {
+        let no_blackjack = Ratio::new(1, 1) - self.black_jack;
+        if *no_blackjack.numer() == 0 {
+            return Ok(DealerTotalProb::new());
+        }
+
+        Ok(DealerTotalProb {
+            s17: checked_div(&self.s17, &no_blackjack)?,
+            s18: checked_div(&self.s18, &no_blackjack)?,
+            s19: checked_div(&self.s19, &no_blackjack)?,
+            s20: checked_div(&self.s20, &no_blackjack)?,
+            s21: checked_div(&self.s21, &no_blackjack)?,
+            black_jack: Ratio::new(0, 1),
+            bust: checked_div(&self.bust, &no_blackjack)?,
+        })
+    }
+
+    // other の各確率に prob を掛けて足し込む
+    pub(crate) fn add_all_scaled(
+        &mut self,
+        other: &DealerTotalProb,
+        prob: &Ratio<u128>,
+    ) -> Result<(), Error> {
+        for (total, p) in other.iter() {
+            if *p.numer() != 0 {
+                self.add_scaled(total, &checked_mul(p, prob)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn iter(&self) -> vec::IntoIter<(&HandTotal, &Ratio<u128>)> {
         vec![
             (&HandTotal::Value(17), &self.s17),
             (&HandTotal::Value(18), &self.s18),
@@ -427,18 +649,19 @@ impl DealerTotalProb {
     }
 }
 
-#[derive(Deserialize, Serialize, Clone)]
+// アップカードごとの DealerTotalProb
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct DealerHandProb {
-    ace: DealerTotalProb,
-    n2: DealerTotalProb,
-    n3: DealerTotalProb,
-    n4: DealerTotalProb,
-    n5: DealerTotalProb,
-    n6: DealerTotalProb,
-    n7: DealerTotalProb,
-    n8: DealerTotalProb,
-    n9: DealerTotalProb,
-    face: DealerTotalProb,
+    pub ace: DealerTotalProb,
+    pub n2: DealerTotalProb,
+    pub n3: DealerTotalProb,
+    pub n4: DealerTotalProb,
+    pub n5: DealerTotalProb,
+    pub n6: DealerTotalProb,
+    pub n7: DealerTotalProb,
+    pub n8: DealerTotalProb,
+    pub n9: DealerTotalProb,
+    pub face: DealerTotalProb,
 }
 
 impl DealerHandProb {
@@ -472,7 +695,7 @@ impl DealerHandProb {
         }
     }
 
-    pub(crate) fn get(&self, card: Card) -> &DealerTotalProb {
+    pub fn get(&self, card: Card) -> &DealerTotalProb {
         match card {
             Card::Ace => &self.ace,
             Card::N2 => &self.n2,
@@ -486,6 +709,12 @@ impl DealerHandProb {
             Card::Face => &self.face,
         }
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Card, &DealerTotalProb)> {
+        Card::ALL
+            .into_iter()
+            .map(move |card| (card, self.get(card)))
+    }
 }
 
 #[cfg(test)]
@@ -494,6 +723,38 @@ mod tests {
     use num_rational::Ratio;
     use std::vec;
 
+    #[test]
+    fn test_try_remove() {
+        let deck = Deck::new_from_strs(&vec!["A", "2"]);
+        assert_eq!(
+            deck.try_remove(Card::Ace),
+            Ok(Deck::new_from_strs(&vec!["2"]))
+        );
+        assert!(matches!(
+            deck.try_remove(Card::N3),
+            Err(Error::InvalidDeck(_))
+        ));
+
+        let mut deck = deck;
+        assert!(deck.try_remove_mut(Card::N3).is_err());
+        assert_eq!(deck, Deck::new_from_strs(&vec!["A", "2"]));
+
+        assert!(deck
+            .try_remove_deck(&Deck::new_from_strs(&vec!["A", "A"]))
+            .is_err());
+        assert_eq!(
+            Deck::try_new_from_strs(&vec!["A", "Q"]),
+            Ok(Deck::new_from_strs(&vec!["A", "K"]))
+        );
+        assert!(Deck::try_new_from_strs(&vec!["A", "Z"]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "no N3 left in the deck")]
+    fn test_remove_missing_card() {
+        Deck::new_from_strs(&vec!["A"]).remove(Card::N3);
+    }
+
     #[test]
     fn test_draw_probability() {
         let deck_cards = Deck::new(1); // 1デッキ
@@ -514,7 +775,7 @@ mod tests {
     fn test_card_to_vec() {
         let deck = Deck::new_from_strs(&vec!["A", "2", "3", "T"]);
         let cards: Cards = deck.into();
-        assert_eq!(cards.cards.contains(&Card::Ace), true);
+        assert!(cards.cards.contains(&Card::Ace));
     }
 
     #[test]
@@ -528,7 +789,7 @@ mod tests {
     fn test_cards_draw_probability() {
         let deck_cards = Deck::new(1); // 1デッキ
         let cards = Deck::new_from_strs(&vec!["A"]);
-        let prob = deck_cards.deck_draw_probability(&cards);
+        let prob = deck_cards.deck_draw_probability(&cards).unwrap();
         let expected_prob = Ratio::new(4, 52);
         assert_eq!(prob, expected_prob);
     }
@@ -537,7 +798,7 @@ mod tests {
     fn test_cards_draw_probability1() {
         let deck_cards = Deck::new(1); // 1デッキ
         let cards = Deck::new_from_strs(&vec!["A", "2", "3", "4"]);
-        let prob = deck_cards.deck_draw_probability(&cards);
+        let prob = deck_cards.deck_draw_probability(&cards).unwrap();
         let expected_prob =
             Ratio::new(4, 52) * Ratio::new(4, 51) * Ratio::new(4, 50) * Ratio::new(4, 49);
         assert_eq!(prob, expected_prob);
@@ -547,7 +808,7 @@ mod tests {
     fn test_cards_draw_probability2() {
         let deck_cards = Deck::new(1); // 1デッキ
         let cards = Deck::new_from_strs(&vec!["A", "2", "3", "T"]);
-        let prob = deck_cards.deck_draw_probability(&cards);
+        let prob = deck_cards.deck_draw_probability(&cards).unwrap();
         let expected_prob =
             Ratio::new(4, 52) * Ratio::new(4, 51) * Ratio::new(4, 50) * Ratio::new(16, 49);
         assert_eq!(prob, expected_prob);
@@ -557,7 +818,7 @@ mod tests {
     fn test_cards_draw_probability3() {
         let deck_cards = Deck::new(1); // 1デッキ
         let cards = Deck::new_from_strs(&vec!["A", "A", "A", "A", "A"]);
-        let prob = deck_cards.deck_draw_probability(&cards);
+        let prob = deck_cards.deck_draw_probability(&cards).unwrap();
         let expected_prob = Ratio::new(0, 1);
         assert_eq!(prob, expected_prob);
     }
@@ -566,7 +827,7 @@ mod tests {
     fn test_cards_draw_probability4() {
         let deck_cards = Deck::new(1); // 1デッキ
         let cards = Deck::new_from_strs(&vec!["A", "A"]);
-        let prob = deck_cards.deck_draw_probability(&cards);
+        let prob = deck_cards.deck_draw_probability(&cards).unwrap();
         let expected_prob = Ratio::new(4, 52) * Ratio::new(3, 51);
         assert_eq!(prob, expected_prob);
     }
@@ -575,7 +836,7 @@ mod tests {
     fn test_cards_draw_probability5() {
         let deck_cards = Deck::new(6);
         let cards = Deck::new_from_strs(&vec!["A", "A", "A", "A", "A", "A"]);
-        let prob = deck_cards.deck_draw_probability(&cards);
+        let prob = deck_cards.deck_draw_probability(&cards).unwrap();
         let expected_prob = Ratio::new(4 * 6, 52 * 6)
             * Ratio::new(4 * 6 - 1, 52 * 6 - 1)
             * Ratio::new(4 * 6 - 2, 52 * 6 - 2)
@@ -585,10 +846,93 @@ mod tests {
         assert_eq!(prob, expected_prob);
     }
 
+    #[test]
+    fn test_cards_draw_probability_large_shoe() {
+        // 32デッキから12枚: 約分前の分母 1664 * 1663 * ... は u128 に収まらない
+        let deck_cards = Deck::new(32);
+        let cards = Deck::new_from_strs(&vec![
+            "A", "A", "A", "A", "2", "2", "2", "2", "3", "3", "T", "T",
+        ]);
+        let factors = deck_cards.draw_factors(&cards).unwrap();
+        assert_eq!(draw_probability_checked(&factors), None);
+
+        let prob = deck_cards.deck_draw_probability(&cards).unwrap();
+        let expected_prob = draw_probability_big(&factors);
+        assert_eq!(BigInt::from(*prob.numer()), *expected_prob.numer());
+        assert_eq!(BigInt::from(*prob.denom()), *expected_prob.denom());
+    }
+
+    #[test]
+    fn test_cards_draw_probability_longest_patterns() {
+        // アップカードごとに一番枚数の多いディーラーのパターン
+        for decks in [8, 12, 24] {
+            let deck_cards = Deck::new(decks);
+            for card in Card::ALL {
+                let deck_cards = deck_cards.remove(card);
+                let longest = [
+                    HandTotal::Value(17),
+                    HandTotal::Value(18),
+                    HandTotal::Value(19),
+                    HandTotal::Value(20),
+                    HandTotal::Value(21),
+                    HandTotal::Burst,
+                ]
+                .iter()
+                .flat_map(|total| DealerHandPatterns::standard().get(card, total).keys())
+                .max_by_key(|pattern| pattern.total_cards())
+                .unwrap();
+
+                let prob = deck_cards.deck_draw_probability(longest).unwrap();
+                let expected_prob =
+                    draw_probability_big(&deck_cards.draw_factors(longest).unwrap());
+                assert_eq!(
+                    Ratio::new(BigInt::from(*prob.numer()), BigInt::from(*prob.denom())),
+                    expected_prob,
+                    "decks: {}, up card: {:?}, pattern: {:?}",
+                    decks,
+                    card,
+                    longest
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_dealer_probs_large_shoe() {
+        // 一番長いパターンも含めて全部足す. 途中の和が u128 で溢れても BigInt で計算し直す
+        for decks in [8, 12, 24, 64] {
+            for card in Card::ALL {
+                let deck = Deck::new(decks).remove(card);
+                let prob = deck
+                    .calc_dealer_hand_prob_with_patterns(DealerHandPatterns::standard())
+                    .unwrap();
+                let total = prob
+                    .get(card)
+                    .iter()
+                    .try_fold(Ratio::new(0, 1), |sum, (_, p)| checked_add(&sum, p))
+                    .unwrap();
+                assert_eq!(
+                    total,
+                    Ratio::new(1, 1),
+                    "decks: {}, up card: {:?}",
+                    decks,
+                    card
+                );
+            }
+        }
+
+        // 約分しても収まらないほど大きいdeckは panic せずに Error::Overflow
+        let deck = Deck::new(1000);
+        assert!(matches!(
+            deck.calc_dealer_hand_prob_with_patterns(DealerHandPatterns::standard()),
+            Err(Error::Overflow(_))
+        ));
+    }
+
     #[test]
     fn test_generate_prob2() {
         let deck_cards = Deck::new(1); // 1デッキ
-        let prob = deck_cards.dealer_probs();
+        let prob = deck_cards.dealer_probs().unwrap();
 
         // prob.iter().for_each(|(card, total_value_prob)| {
         //     total_value_prob.iter().for_each(|(total_value, prob)| {
@@ -631,7 +975,7 @@ mod tests {
     #[test]
     fn test_generate_prob3() {
         let deck_cards = Deck::new(6); // 1デッキ
-        let prob = deck_cards.dealer_probs();
+        let prob = deck_cards.dealer_probs().unwrap();
 
         // 合計が1.0になるか
         for card in [
@@ -667,11 +1011,62 @@ mod tests {
         // );
     }
 
+    #[test]
+    fn test_dealer_probs_no_blackjack() {
+        let deck = Deck::new(1);
+        let prob = deck.dealer_probs().unwrap();
+
+        for card in [Card::Ace, Card::Face] {
+            let total_prob = prob.get(card);
+            let given = deck.dealer_probs_no_blackjack(card).unwrap();
+            let no_blackjack = Ratio::new(1, 1) - total_prob.black_jack;
+
+            assert_eq!(given.black_jack, Ratio::new(0, 1));
+            assert_eq!(given.s17, total_prob.s17 / no_blackjack);
+            assert_eq!(given.bust, total_prob.bust / no_blackjack);
+            assert_eq!(
+                given.iter().map(|(_, p)| *p).sum::<Ratio<u128>>(),
+                Ratio::new(1, 1)
+            );
+        }
+
+        // BJがありえないアップカードでは変わらない
+        assert_eq!(
+            deck.dealer_probs_no_blackjack(Card::N6).unwrap(),
+            *prob.get(Card::N6)
+        );
+    }
+
+    #[test]
+    fn test_dealer_total_prob_before_up_card() {
+        let deck = Deck::new(1);
+        let prob = deck.dealer_total_prob_before_up_card().unwrap();
+
+        assert_eq!(
+            prob.iter().map(|(_, p)| *p).sum::<Ratio<u128>>(),
+            Ratio::new(1, 1)
+        );
+        // BJになるのは A→10 か 10→A の 2 * 4/52 * 16/51
+        assert_eq!(prob.black_jack, Ratio::new(2 * 4 * 16, 52 * 51));
+        assert_eq!(
+            deck.dealer_total_prob(Card::N6)
+                .unwrap()
+                .get(&HandTotal::Burst),
+            Some(&deck.dealer_probs().unwrap().n6.bust)
+        );
+        assert_eq!(
+            deck.dealer_total_prob(Card::N6)
+                .unwrap()
+                .get(&HandTotal::Value(16)),
+            None
+        );
+    }
+
     #[test]
     fn test_deck_eq1() {
         let cards = vec!["A", "2", "3", "4", "2", "2", "A"];
         let deck1 = Deck::new_from_strs(&cards);
-        let deck2 = Deck::new_from_cards(&&Cards::new_from_strs(&cards));
+        let deck2 = Deck::new_from_cards(&Cards::new_from_strs(&cards));
         assert_eq!(deck1, deck2);
     }
 }
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
 use crate::models::{Card, Cards, Deck};
+use crate::Error;
 use smallvec::smallvec;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Hash, PartialEq, Eq)]
@@ -99,6 +100,13 @@ impl Hand {
     }
 
     pub(crate) fn add_mut(&mut self, card: Card) {
+        if let Err(e) = self.try_add_mut(card) {
+            panic!("{}", e);
+        }
+    }
+
+    // バーストやBJのハンドにはもう引けないので InvalidHand. その場合 self は変わらない
+    pub fn try_add_mut(&mut self, card: Card) -> Result<(), Error> {
         *self = match self {
             Hand::Hard(HandTotal::Value(v)) => {
                 // aceのとき、10以下なら11. 11以上なら1
@@ -134,8 +142,20 @@ impl Hand {
             }
             Hand::Pair(c) => Cards::from_smallvec(smallvec![*c, *c, card]).into(),
             Hand::None => Cards::from_smallvec(smallvec![card]).into(),
-            _ => panic!("へんなもの追加しないで self: {:?} card {:?}", self, card),
+            _ => {
+                return Err(Error::InvalidHand(format!(
+                    "cannot add {:?} to {:?}",
+                    card, self
+                )))
+            }
         };
+        Ok(())
+    }
+
+    pub fn try_add(&self, card: Card) -> Result<Self, Error> {
+        let mut new_hand = self.clone();
+        new_hand.try_add_mut(card)?;
+        Ok(new_hand)
     }
 
     pub fn add(&self, card: Card) -> Self {
@@ -317,6 +337,22 @@ mod tests {
         assert_eq!(hand, Hand::Hard(HandTotal::Value(12)));
     }
 
+    #[test]
+    fn test_hand_try_add() {
+        assert_eq!(
+            Hand::Pair(Card::Ace).try_add(Card::N6),
+            Ok(Hand::Soft(HandTotal::Value(18)))
+        );
+        assert!(matches!(
+            Hand::Hard(HandTotal::Burst).try_add(Card::N2),
+            Err(Error::InvalidHand(_))
+        ));
+        assert!(matches!(
+            Hand::Soft(HandTotal::BlackJack).try_add(Card::N2),
+            Err(Error::InvalidHand(_))
+        ));
+    }
+
     #[test]
     fn test_cards_to_hand1() {
         let cards: Hand = Cards::new_from_strs(&vec!["A", "2", "3", "T"]).into();
@@ -1,5 +1,6 @@
 pub mod card;
 pub mod cards;
+pub mod dealer_engine;
 pub mod dealer_pattern;
 pub mod deck;
 pub mod hand;
@@ -8,8 +9,9 @@ pub mod rule;
 
 pub use card::Card;
 pub use cards::Cards;
-pub use dealer_pattern::DealerHandDeckMap;
-pub use deck::Deck;
+pub use dealer_engine::{DealerCardCountProb, DealerProbEngine};
+pub use dealer_pattern::{DealerHandDeckMap, DealerHandPatterns};
+pub use deck::{DealerHandProb, DealerTotalProb, Deck};
 pub use hand::{Hand, HandTotal};
 pub use pre_round_pattern::PreRoundPattern;
 pub use rule::Rule;
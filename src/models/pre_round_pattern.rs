@@ -68,7 +68,7 @@ impl PreRoundPattern {
     }
 
     pub(crate) fn all() -> &'static Vec<PreRoundPattern> {
-        &*PRE_ROUND_PATTERN_REPOSITORY
+        &PRE_ROUND_PATTERN_REPOSITORY
     }
 }
 #[cfg(test)]
@@ -76,6 +76,7 @@ mod tests {
     use num_rational::Ratio;
 
     use super::*;
+    use crate::models::deck::{checked_add, checked_mul};
     use std::collections::HashSet;
 
     #[test]
@@ -138,10 +139,12 @@ mod tests {
         let patterns = PreRoundPattern::all();
 
         let deck = Deck::new(8);
-        let s: Ratio<u128> = patterns
-            .iter()
-            .map(|pattern| deck.deck_draw_probability(&pattern.all_deck) * pattern.weight as u128)
-            .sum();
+        let mut s: Ratio<u128> = Ratio::new(0, 1);
+        for pattern in patterns {
+            let prob = deck.deck_draw_probability(&pattern.all_deck).unwrap();
+            let prob = checked_mul(&prob, &Ratio::from(pattern.weight as u128)).unwrap();
+            s = checked_add(&s, &prob).unwrap();
+        }
         assert_eq!(s, Ratio::new(1, 1));
     }
 }
@@ -1,6 +1,8 @@
+use crate::Error;
 use num::One;
 use num_rational::Ratio;
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+use serde::{Deserialize, Serialize};
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Rule {
     pub decks: usize,              // デッキ数
     pub without_9_t: bool,         // 9と10を除くか
@@ -11,6 +13,7 @@ pub struct Rule {
     pub double_after_split: bool,  // スプリット後にダブルできるか
     pub hit_split_aces: bool,      // スプリットエースにヒットできるか
     pub six_card_charlie: bool,    // 6枚のカードで勝てるか
+    pub dealer_peek: bool,         // A, 10のときにディーラーがBJを確認するか (US peek)
     //4-17, 18, 19, 20, 21, BJごとに倍率がある
     pub multiplier_black_jack: Ratio<usize>,
     pub multiplier_lteq_17: Ratio<usize>,
@@ -19,3 +22,85 @@ pub struct Rule {
     pub multiplier_20: Ratio<usize>,
     pub multiplier_21: Ratio<usize>,
 }
+
+impl Rule {
+    // Evolution の Classic Blackjack (8デッキ, S17, BJ 3:2)
+    pub fn evolution_classic() -> Self {
+        Rule {
+            decks: 8,
+            without_9_t: false,
+            free_split_9_10_11: false,
+            free_double_9_10_11: false,
+            triple_double: false,
+            quad_double: false,
+            double_after_split: true,
+            hit_split_aces: false,
+            six_card_charlie: false,
+            dealer_peek: false,
+            multiplier_black_jack: Ratio::new(3, 2),
+            multiplier_lteq_17: Ratio::one(),
+            multiplier_18: Ratio::one(),
+            multiplier_19: Ratio::one(),
+            multiplier_20: Ratio::one(),
+            multiplier_21: Ratio::one(),
+        }
+    }
+
+    // EVの計算がまだ対応していない組み合わせは UnsupportedRule
+    pub fn validate(&self) -> Result<(), Error> {
+        let unsupported = [
+            (self.without_9_t, "without_9_t"),
+            (self.free_split_9_10_11, "free_split_9_10_11"),
+            (self.free_double_9_10_11, "free_double_9_10_11"),
+            (self.triple_double, "triple_double"),
+            (self.quad_double, "quad_double"),
+            (self.hit_split_aces, "hit_split_aces"),
+            (
+                self.multiplier_black_jack != Ratio::new(3, 2),
+                "multiplier_black_jack other than 3:2",
+            ),
+            (
+                [
+                    self.multiplier_lteq_17,
+                    self.multiplier_18,
+                    self.multiplier_19,
+                    self.multiplier_20,
+                    self.multiplier_21,
+                ]
+                .iter()
+                .any(|multiplier| !multiplier.is_one()),
+                "total multipliers other than 1",
+            ),
+        ];
+        if let Some((_, name)) = unsupported.iter().find(|(enabled, _)| *enabled) {
+            return Err(Error::UnsupportedRule(name.to_string()));
+        }
+        if self.decks == 0 {
+            return Err(Error::UnsupportedRule(
+                "decks must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Rule::evolution_classic().validate(), Ok(()));
+
+        let mut rule = Rule::evolution_classic();
+        rule.triple_double = true;
+        assert_eq!(
+            rule.validate(),
+            Err(Error::UnsupportedRule("triple_double".to_string()))
+        );
+
+        let mut rule = Rule::evolution_classic();
+        rule.multiplier_black_jack = Ratio::new(6, 5);
+        assert!(matches!(rule.validate(), Err(Error::UnsupportedRule(_))));
+    }
+}
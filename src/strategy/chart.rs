@@ -0,0 +1,327 @@
+use crate::game::{Action, ActionEV, BlackJackGame, EvControl, EvNumber, Solver};
+use crate::models::{Card, Cards, Deck, Hand, Rule};
+use crate::Error;
+
+use num::bigint::BigInt;
+use num::rational::Ratio;
+use smallvec::smallvec;
+use tokio_util::sync::CancellationToken;
+
+// チャートの列. ディーラーのアップカード 2..10, A
+pub const DEALER_CARDS: [Card; 10] = [
+    Card::N2,
+    Card::N3,
+    Card::N4,
+    Card::N5,
+    Card::N6,
+    Card::N7,
+    Card::N8,
+    Card::N9,
+    Card::Face,
+    Card::Ace,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowKind {
+    Hard,
+    Soft,
+    Pair,
+}
+
+#[derive(Debug, Clone)]
+pub struct StrategyCell<N = Ratio<BigInt>> {
+    pub action_ev: ActionEV<N>,
+    pub best: Action,
+    pub second: Option<Action>,
+    // 1番目と2番目の行動のEVの差. 選べる行動が1つしかなければ None
+    pub margin: Option<N>,
+    // サレンダーのEV (-1/2). サレンダーを計算していなければ None
+    pub surrender: Option<N>,
+}
+
+impl<N: EvNumber> StrategyCell<N> {
+    pub fn new(action_ev: ActionEV<N>) -> Self {
+        let ranked = action_ev.ranked();
+        let (best, best_ev) = ranked[0].clone();
+        let second = ranked.get(1).cloned();
+        StrategyCell {
+            best,
+            second: second.as_ref().map(|(action, _)| *action),
+            margin: second.map(|(_, ev)| best_ev - ev),
+            action_ev,
+            surrender: None,
+        }
+    }
+
+    // サレンダーできるマスにする. best などはサレンダー以外の行動のまま
+    pub fn with_surrender(mut self, surrender: N) -> Self {
+        self.surrender = Some(surrender);
+        self
+    }
+
+    // ダブルやスプリットができないとき (3枚目以降) の行動. ヒットかスタンドの良い方
+    pub fn fallback(&self) -> Action {
+        match &self.action_ev.hit {
+            Some(hit) if *hit > self.action_ev.stand => Action::Hit,
+            _ => Action::Stand,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StrategyRow<N = Ratio<BigInt>> {
+    pub kind: RowKind,
+    pub hand: Hand,
+    // EVの計算に使った代表の手札. シューからはこのカードを抜いて計算する
+    pub cards: Cards,
+    // DEALER_CARDS の順. シューに残っていなくて配れない組み合わせは None
+    pub cells: Vec<Option<StrategyCell<N>>>,
+}
+
+impl<N> StrategyRow<N> {
+    pub fn cell(&self, dealer_card: Card) -> Option<&StrategyCell<N>> {
+        let index = DEALER_CARDS.iter().position(|&card| card == dealer_card)?;
+        self.cells[index].as_ref()
+    }
+}
+
+// hard 5-21, soft 13-21, ペア 2-2..A-A とディーラーのアップカードの表
+#[derive(Debug, Clone)]
+pub struct StrategyChart<N = Ratio<BigInt>> {
+    pub rule: Rule,
+    pub deck: Deck,
+    pub hard: Vec<StrategyRow<N>>,
+    pub soft: Vec<StrategyRow<N>>,
+    pub pairs: Vec<StrategyRow<N>>,
+}
+
+impl StrategyChart {
+    pub fn generate(
+        rule: &Rule,
+        deck: &Deck,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Self, Error> {
+        Self::generate_as(rule, deck, cancellation_token)
+    }
+}
+
+impl<N: EvNumber> StrategyChart<N> {
+    // 数値型を指定して作る. f64 なら厳密ではないが速い
+    pub fn generate_as(
+        rule: &Rule,
+        deck: &Deck,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Self, Error> {
+        Self::generate_in(
+            Solver::global(),
+            rule,
+            deck,
+            &EvControl::from(cancellation_token),
+        )
+    }
+
+    pub(crate) fn generate_in(
+        solver: &Solver,
+        rule: &Rule,
+        deck: &Deck,
+        control: &EvControl,
+    ) -> Result<Self, Error> {
+        rule.validate()?;
+
+        let row = |kind: RowKind, cards: Cards| -> Result<StrategyRow<N>, Error> {
+            let hand = match kind {
+                RowKind::Hard | RowKind::Soft => hand_total_only(&cards),
+                RowKind::Pair => cards.clone().into(),
+            };
+            let cells = DEALER_CARDS
+                .iter()
+                .map(|&dealer_card| {
+                    cell_in(solver, rule, deck, dealer_card, &cards, &hand, control)
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            Ok(StrategyRow {
+                kind,
+                hand,
+                cards,
+                cells,
+            })
+        };
+
+        Ok(StrategyChart {
+            rule: rule.clone(),
+            deck: deck.clone(),
+            hard: (5..=21)
+                .map(|total| row(RowKind::Hard, hard_cards(total)))
+                .collect::<Result<_, _>>()?,
+            soft: (13..=21)
+                .map(|total| row(RowKind::Soft, soft_cards(total)))
+                .collect::<Result<_, _>>()?,
+            pairs: DEALER_CARDS
+                .iter()
+                .map(|&card| row(RowKind::Pair, Cards::from_smallvec(smallvec![card, card])))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &StrategyRow<N>> {
+        self.hard
+            .iter()
+            .chain(self.soft.iter())
+            .chain(self.pairs.iter())
+    }
+}
+
+fn cell_in<N: EvNumber>(
+    solver: &Solver,
+    rule: &Rule,
+    deck: &Deck,
+    dealer_card: Card,
+    cards: &Cards,
+    hand: &Hand,
+    control: &EvControl,
+) -> Result<Option<StrategyCell<N>>, Error> {
+    let mut dealt = Deck::new_from_cards(cards);
+    dealt.add_mut(dealer_card);
+    // シューから配れない組み合わせ
+    let Ok(deck_cards) = deck.try_remove_deck(&dealt) else {
+        return Ok(None);
+    };
+    if deck_cards.total_cards() == 0 {
+        return Ok(None);
+    }
+
+    let game = BlackJackGame::new(
+        rule.clone(),
+        dealer_card,
+        hand.clone(),
+        deck_cards,
+        cards.cards.len(),
+    );
+    let action_ev = game.action_ev_in::<N>(solver, control)?;
+    Ok(Some(StrategyCell::new(action_ev)))
+}
+
+// ペアの行でなければ、10-10 なども合計だけのハンドとして扱う
+fn hand_total_only(cards: &Cards) -> Hand {
+    match Hand::from(cards.clone()) {
+        Hand::Pair(card) => Hand::None.add(card).add(card),
+        hand => hand,
+    }
+}
+
+// 合計 total の hard の代表. 大きいカードから2枚. 21 は3枚 (10, 9, 2)
+fn hard_cards(total: usize) -> Cards {
+    if total == 21 {
+        return Cards::from_smallvec(smallvec![Card::Face, Card::N9, Card::N2]);
+    }
+    let first = (total - 2).min(10);
+    [first, total - first].into_iter().map(card_of).collect()
+}
+
+// 合計 total の soft の代表. A と残り1枚. 21 は BJ にならないように3枚 (A, 6, 4)
+fn soft_cards(total: usize) -> Cards {
+    if total == 21 {
+        return Cards::from_smallvec(smallvec![Card::Ace, Card::N6, Card::N4]);
+    }
+    Cards::from_smallvec(smallvec![Card::Ace, card_of(total - 11)])
+}
+
+fn card_of(value: usize) -> Card {
+    *Card::ALL
+        .iter()
+        .find(|card| card.value() == value)
+        .unwrap_or_else(|| panic!("no card with value {}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::HandTotal;
+
+    #[test]
+    fn test_representative_cards() {
+        for total in 5..=21 {
+            assert_eq!(
+                hand_total_only(&hard_cards(total)),
+                Hand::Hard(HandTotal::Value(total))
+            );
+        }
+        for total in 13..=21 {
+            assert_eq!(
+                hand_total_only(&soft_cards(total)),
+                Hand::Soft(HandTotal::Value(total))
+            );
+        }
+    }
+
+    // 1デッキでも時間がかかるので、各ランク2枚 (10は8枚) の半分のデッキで試す
+    fn half_deck() -> Deck {
+        let mut cards = vec![];
+        for card in ["A", "2", "3", "4", "5", "6", "7", "8", "9"] {
+            cards.extend([card, card]);
+        }
+        cards.extend(["10"; 8]);
+        Deck::new_from_strs(&cards)
+    }
+
+    #[test]
+    fn test_strategy_chart() {
+        let token = CancellationToken::new();
+        let mut rule = Rule::evolution_classic();
+        rule.decks = 1;
+        let chart = StrategyChart::<f64>::generate_as(&rule, &half_deck(), &token).unwrap();
+
+        assert_eq!(chart.hard.len(), 17);
+        assert_eq!(chart.soft.len(), 9);
+        assert_eq!(chart.pairs.len(), 10);
+        assert!(chart
+            .rows()
+            .all(|row| row.cells.len() == DEALER_CARDS.len()));
+
+        let hard = |total: usize| &chart.hard[total - 5];
+        let pair = |card: Card| {
+            chart
+                .pairs
+                .iter()
+                .find(|row| row.hand == Hand::Pair(card))
+                .unwrap()
+        };
+
+        assert_eq!(hard(17).cell(Card::Face).unwrap().best, Action::Stand);
+        assert_eq!(hard(16).cell(Card::Face).unwrap().best, Action::Hit);
+        assert_eq!(hard(11).cell(Card::N6).unwrap().best, Action::Double);
+        assert_eq!(hard(13).cell(Card::N4).unwrap().best, Action::Stand);
+        assert_eq!(pair(Card::Ace).cell(Card::N6).unwrap().best, Action::Split);
+        assert_eq!(pair(Card::N8).cell(Card::N7).unwrap().best, Action::Split);
+        assert_eq!(pair(Card::Face).cell(Card::N6).unwrap().best, Action::Stand);
+
+        // 21 はスタンドしかない
+        let cell = hard(21).cell(Card::N9).unwrap();
+        assert_eq!(cell.best, Action::Stand);
+        for row in chart.rows() {
+            for cell in row.cells.iter().flatten() {
+                if let Some(margin) = cell.margin {
+                    assert!(margin >= 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strategy_chart_depleted_shoe() {
+        let token = CancellationToken::new();
+        let mut rule = Rule::evolution_classic();
+        rule.decks = 1;
+        let deck = half_deck().remove(Card::Ace);
+        let chart = StrategyChart::<f64>::generate_as(&rule, &deck, &token).unwrap();
+
+        // A は1枚しか残っていないので A-A は配れない
+        let aces = chart
+            .pairs
+            .iter()
+            .find(|row| row.hand == Hand::Pair(Card::Ace))
+            .unwrap();
+        assert!(aces.cells.iter().all(|cell| cell.is_none()));
+        assert!(chart.hard[16 - 5].cell(Card::Ace).is_some());
+    }
+}
@@ -0,0 +1,246 @@
+use crate::game::{Action, ActionEV, BlackJackGame, EvControl, EvNumber, Solver};
+use crate::models::{Card, Cards, Deck, Hand, HandTotal};
+use crate::strategy::{StrategyChart, StrategyRow, DEALER_CARDS};
+use crate::Error;
+
+use smallvec::SmallVec;
+use tokio_util::sync::CancellationToken;
+
+// 合計だけで決めたチャートの行動と、カードの組み合わせまで見た最適な行動が違うところ
+#[derive(Debug, Clone)]
+pub struct CompositionException<N> {
+    pub cards: Cards,
+    pub hand: Hand,
+    pub dealer_card: Card,
+    // チャートの行動. この組み合わせで選べなければヒットかスタンドの良い方
+    pub chart_action: Action,
+    pub best_action: Action,
+    // 最適な行動にしたときに増えるEV (1ハンドあたり)
+    pub gain: N,
+    pub action_ev: ActionEV<N>,
+}
+
+impl<N: EvNumber> StrategyChart<N> {
+    // hard / soft の行ごとに、max_cards 枚までの全ての組み合わせを調べる. gain の大きい順
+    // ペアの2枚はペアの行があるので除く. 枚数が増えるほど組み合わせも計算も増える
+    pub fn composition_exceptions(
+        &self,
+        max_cards: usize,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Vec<CompositionException<N>>, Error> {
+        self.composition_exceptions_in(
+            Solver::global(),
+            max_cards,
+            &EvControl::from(cancellation_token),
+        )
+    }
+
+    pub(crate) fn composition_exceptions_in(
+        &self,
+        solver: &Solver,
+        max_cards: usize,
+        control: &EvControl,
+    ) -> Result<Vec<CompositionException<N>>, Error> {
+        let mut exceptions = vec![];
+        for cards in compositions(&self.deck, max_cards) {
+            let hand = Hand::from(cards.clone());
+            let Some(row) = self.row_for(&hand) else {
+                continue;
+            };
+            for &dealer_card in DEALER_CARDS.iter() {
+                control.check()?;
+                if row.cell(dealer_card).is_none() {
+                    continue;
+                }
+                if let Some(exception) =
+                    self.exception_in(solver, row, &cards, &hand, dealer_card, control)?
+                {
+                    exceptions.push(exception);
+                }
+            }
+        }
+        exceptions.sort_by(|a, b| {
+            b.gain
+                .partial_cmp(&a.gain)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        Ok(exceptions)
+    }
+
+    fn row_for(&self, hand: &Hand) -> Option<&StrategyRow<N>> {
+        let rows = match hand {
+            Hand::Hard(_) => &self.hard,
+            Hand::Soft(_) => &self.soft,
+            _ => return None,
+        };
+        rows.iter().find(|row| row.hand == *hand)
+    }
+
+    fn exception_in(
+        &self,
+        solver: &Solver,
+        row: &StrategyRow<N>,
+        cards: &Cards,
+        hand: &Hand,
+        dealer_card: Card,
+        control: &EvControl,
+    ) -> Result<Option<CompositionException<N>>, Error> {
+        let Some(cell) = row.cell(dealer_card) else {
+            return Ok(None);
+        };
+
+        let mut dealt = Deck::new_from_cards(cards);
+        dealt.add_mut(dealer_card);
+        let Ok(deck_cards) = self.deck.try_remove_deck(&dealt) else {
+            return Ok(None);
+        };
+        if deck_cards.total_cards() == 0 {
+            return Ok(None);
+        }
+
+        let game = BlackJackGame::new(
+            self.rule.clone(),
+            dealer_card,
+            hand.clone(),
+            deck_cards,
+            cards.cards.len(),
+        );
+        let action_ev = game.action_ev_in::<N>(solver, control)?;
+
+        let chart_action = if action_ev.get(cell.best).is_some() {
+            cell.best
+        } else {
+            cell.fallback()
+        };
+        let best_action = action_ev.best_action();
+        let (Some(chart_ev), Some(best_ev)) =
+            (action_ev.get(chart_action), action_ev.get(best_action))
+        else {
+            return Ok(None);
+        };
+        if best_action == chart_action || *best_ev <= *chart_ev {
+            return Ok(None);
+        }
+
+        Ok(Some(CompositionException {
+            cards: cards.clone(),
+            hand: hand.clone(),
+            dealer_card,
+            chart_action,
+            best_action,
+            gain: best_ev.clone() - chart_ev.clone(),
+            action_ev,
+        }))
+    }
+}
+
+// deck から配れる、2枚以上 max_cards 枚以下で 21 を超えない組み合わせ
+// 並びは違っても同じ組み合わせは1回だけ. 2枚のペアと BJ は除く
+fn compositions(deck: &Deck, max_cards: usize) -> Vec<Cards> {
+    fn walk(
+        deck: &Deck,
+        max_cards: usize,
+        start: usize,
+        cards: &mut SmallVec<[Card; 21]>,
+        out: &mut Vec<Cards>,
+    ) {
+        if cards.len() >= 2 {
+            let composition = Cards::from_smallvec(cards.clone());
+            match Hand::from(composition.clone()) {
+                Hand::Hard(HandTotal::Value(_)) | Hand::Soft(HandTotal::Value(_)) => {
+                    out.push(composition)
+                }
+                _ => {}
+            }
+        }
+        if cards.len() == max_cards {
+            return;
+        }
+        for (i, &card) in Card::ALL.iter().enumerate().skip(start) {
+            let used = cards.iter().filter(|&&c| c == card).count();
+            if used >= deck.count(card) {
+                continue;
+            }
+            cards.push(card);
+            let bust = matches!(
+                Hand::from(Cards::from_smallvec(cards.clone())),
+                Hand::Hard(HandTotal::Burst)
+            );
+            if !bust {
+                walk(deck, max_cards, i, cards, out);
+            }
+            cards.pop();
+        }
+    }
+
+    let mut out = vec![];
+    walk(deck, max_cards, 0, &mut SmallVec::new(), &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Rule;
+
+    #[test]
+    fn test_compositions() {
+        let deck = Deck::new(1);
+        let two_cards = compositions(&deck, 2);
+        // 10種類から2枚 (ペアを除く) 45通り. そのうち A,10 は BJ
+        assert_eq!(two_cards.len(), 44);
+
+        let hard_16: Vec<_> = compositions(&deck, 3)
+            .into_iter()
+            .filter(|cards| Hand::from(cards.clone()) == Hand::Hard(HandTotal::Value(16)))
+            .collect();
+        assert!(hard_16
+            .iter()
+            .any(|cards| cards.cards.as_slice() == [Card::N4, Card::N4, Card::N8]));
+        assert!(hard_16
+            .iter()
+            .any(|cards| cards.cards.as_slice() == [Card::N6, Card::Face]));
+        // 8,8 はペアの行
+        assert!(!hard_16
+            .iter()
+            .any(|cards| cards.cards.as_slice() == [Card::N8, Card::N8]));
+
+        // 1枚しかないカードは2回使わない
+        let deck = Deck::new_from_strs(&vec!["4", "8", "10"]);
+        assert!(compositions(&deck, 3).iter().all(|cards| cards
+            .cards
+            .iter()
+            .filter(|&&c| c == Card::N4)
+            .count()
+            <= 1));
+    }
+
+    #[test]
+    fn test_composition_exceptions() {
+        let token = CancellationToken::new();
+        let mut rule = Rule::evolution_classic();
+        rule.decks = 1;
+        let mut cards = vec![];
+        for card in ["A", "2", "3", "4", "5", "6", "7", "8", "9"] {
+            cards.extend([card, card]);
+        }
+        cards.extend(["10"; 8]);
+        let deck = Deck::new_from_strs(&cards);
+
+        let chart = StrategyChart::<f64>::generate_as(&rule, &deck, &token).unwrap();
+        let exceptions = chart.composition_exceptions(2, &token).unwrap();
+
+        assert!(!exceptions.is_empty());
+        assert!(exceptions.windows(2).all(|w| w[0].gain >= w[1].gain));
+        for exception in &exceptions {
+            assert!(exception.gain > 0.0);
+            assert_ne!(exception.best_action, exception.chart_action);
+            let row = chart.row_for(&exception.hand).unwrap();
+            // チャートを作った組み合わせそのものは例外にならない
+            assert_ne!(
+                Deck::new_from_cards(&row.cards),
+                Deck::new_from_cards(&exception.cards)
+            );
+        }
+    }
+}
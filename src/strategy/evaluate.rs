@@ -0,0 +1,500 @@
+use crate::cache::{BoundedCache, CacheWeight};
+use crate::game::round::{player_draw_prob, split_hand};
+use crate::game::{
+    Action, BlackJackGame, EvControl, EvNumber, GameKey, PartialEv, PreBlackJackGame, Solver,
+};
+use crate::models::{Card, Hand, HandTotal};
+use crate::strategy::StrategyChart;
+use crate::Error;
+
+use num::bigint::BigInt;
+use num::rational::Ratio;
+use rayon::prelude::*;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+// 戦略が行動を選ぶ場面. available の中から選ぶこと
+pub struct Decision<'a> {
+    pub game: &'a BlackJackGame,
+    pub available: &'a [Action],
+    // スプリットしてできたハンドか. スプリットした後にヒットした場面でも true
+    pub after_split: bool,
+}
+
+// 最適でなくてもよい、決まった戦略
+// Decision のフィールド (game の全フィールドを含む) が同じなら同じ行動を返すこと
+// 評価中は、ヒットやスプリットの後の場面の結果をそれらをキーにしてキャッシュする
+pub trait Strategy: Sync {
+    fn decide(&self, decision: &Decision<'_>) -> Action;
+}
+
+impl<F> Strategy for F
+where
+    F: Fn(&Decision<'_>) -> Action + Sync,
+{
+    fn decide(&self, decision: &Decision<'_>) -> Action {
+        self(decision)
+    }
+}
+
+// チャートの通りに打つ. 選べない行動ならヒットかスタンドの良い方
+// チャートにない場面 (hard 4 以下, soft 12, 配れなかったマス) は 17 未満ならヒット
+impl<N: EvNumber> Strategy for StrategyChart<N> {
+    fn decide(&self, decision: &Decision<'_>) -> Action {
+        let hand = &decision.game.player_hand;
+        let rows = match hand {
+            Hand::Pair(_) => &self.pairs,
+            Hand::Hard(_) => &self.hard,
+            Hand::Soft(_) => &self.soft,
+            Hand::None => return Action::Hit,
+        };
+        let cell = rows
+            .iter()
+            .find(|row| row.hand == *hand)
+            .and_then(|row| row.cell(decision.game.dealer_card));
+
+        let preferred = match cell {
+            Some(cell) => [cell.best, cell.fallback()],
+            None => match hand.hand_total() {
+                HandTotal::Value(total) if total < 17 => [Action::Hit, Action::Hit],
+                _ => [Action::Stand, Action::Stand],
+            },
+        };
+        preferred
+            .into_iter()
+            .find(|action| decision.available.contains(action))
+            .unwrap_or(Action::Stand)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Stage {
+    // 最初の2枚. 全ての行動を選べる
+    First,
+    // ヒットした後. ヒットかスタンド
+    AfterHit,
+    // スプリットした後. ヒットかスタンド (エースは1枚引いて終わり)
+    AfterSplit,
+    // スプリットしたハンドでヒットした後. ヒットかスタンド
+    AfterSplitHit,
+    SplitAces,
+}
+
+impl Stage {
+    fn after_split(self) -> bool {
+        matches!(
+            self,
+            Stage::AfterSplit | Stage::AfterSplitHit | Stage::SplitAces
+        )
+    }
+
+    // この場面でヒットした後の場面
+    fn hit(self) -> Stage {
+        if self.after_split() {
+            Stage::AfterSplitHit
+        } else {
+            Stage::AfterHit
+        }
+    }
+}
+
+// Evaluator のキャッシュのキー. 戦略に見える Decision のフィールドを全部含める
+// GameKey は Rule が枚数を見ないとき枚数を落とすので、枚数は別に持つ
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DecisionKey {
+    stage: Stage,
+    game: GameKey,
+    player_card_count: usize,
+}
+
+impl CacheWeight for DecisionKey {
+    fn cache_weight(&self) -> usize {
+        size_of::<Self>()
+    }
+}
+
+// 場面ごとに Evaluator が返す値. EV だけなら N そのもの
+// 後悔の集計 (regret.rs) では、その場面から先で間違えた場面の内訳も持つ
+pub(crate) trait Outcome: Clone + Send + Sync + CacheWeight + 'static {
+    type Number: EvNumber;
+
+    fn from_ev(ev: Self::Number) -> Self;
+
+    fn add(self, other: Self) -> Self;
+
+    fn scale(self, factor: Self::Number) -> Self;
+
+    // 戦略が2つ以上の行動から action を選んだ場面. self はその行動の値
+    fn decided(
+        self,
+        _decision: &Decision<'_>,
+        _action: Action,
+        _solver: &Solver,
+        _control: &EvControl,
+    ) -> Result<Self, Error> {
+        Ok(self)
+    }
+}
+
+impl<N: EvNumber> Outcome for N {
+    type Number = N;
+
+    fn from_ev(ev: N) -> Self {
+        ev
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn scale(self, factor: N) -> Self {
+        self * factor
+    }
+}
+
+// BlackJackGame の最適な EV と同じ前提で、行動だけ戦略に任せる
+pub(crate) struct Evaluator<'a, S, V> {
+    solver: &'a Solver,
+    control: &'a EvControl,
+    strategy: &'a S,
+    // ヒットやスプリットの後の場面の結果. 1回の評価の中だけで使う
+    // 戦略ごとに結果が違うので Solver には入れない
+    decided: BoundedCache<DecisionKey, V>,
+}
+
+impl<'a, S: Strategy, V: Outcome> Evaluator<'a, S, V> {
+    pub(crate) fn new(solver: &'a Solver, control: &'a EvControl, strategy: &'a S) -> Self {
+        Evaluator {
+            solver,
+            control,
+            strategy,
+            decided: BoundedCache::new(),
+        }
+    }
+
+    pub(crate) fn ev(&self, game: &BlackJackGame, stage: Stage) -> Result<V, Error> {
+        self.control.check()?;
+
+        let key = match stage {
+            Stage::AfterHit | Stage::AfterSplit | Stage::AfterSplitHit => Some(DecisionKey {
+                stage,
+                game: game.cache_key(),
+                player_card_count: game.player_card_count,
+            }),
+            Stage::First | Stage::SplitAces => None,
+        };
+        if let Some(key) = &key {
+            if let Some(ev) = self.decided.get(key) {
+                return Ok(ev);
+            }
+        }
+
+        let available = available_actions(game, stage);
+        let decision = Decision {
+            game,
+            available: &available,
+            after_split: stage.after_split(),
+        };
+        let action = if available.len() == 1 {
+            available[0]
+        } else {
+            self.strategy.decide(&decision)
+        };
+        if !available.contains(&action) {
+            return Err(Error::InvalidAction(format!(
+                "{:?} is not available for {:?} against {:?}",
+                action, game.player_hand, game.dealer_card
+            )));
+        }
+
+        let mut ev = self.action_ev(game, stage, action)?;
+        if available.len() > 1 {
+            ev = ev.decided(&decision, action, self.solver, self.control)?;
+        }
+        if let Some(key) = key {
+            self.decided.insert(key, ev.clone());
+        }
+        Ok(ev)
+    }
+
+    fn action_ev(&self, game: &BlackJackGame, stage: Stage, action: Action) -> Result<V, Error> {
+        let two = V::Number::from_integer(2);
+        match action {
+            Action::Stand => Ok(V::from_ev(game.get_stand_ev_in(self.solver, self.control)?)),
+            Action::Hit => self.draw(game, |rank, next| {
+                let next = BlackJackGame {
+                    player_hand: game.player_hand.add(rank),
+                    player_card_count: game.player_card_count + 1,
+                    ..next
+                };
+                self.ev(&next, stage.hit())
+            }),
+            // 1枚引いてスタンド. BlackJackGame::double_ev と同じく stand_ev を使う
+            Action::Double => Ok(self
+                .draw(game, |rank, next| {
+                    let next = BlackJackGame {
+                        player_hand: game.player_hand.add(rank),
+                        player_card_count: game.player_card_count + 1,
+                        ..next
+                    };
+                    Ok(V::from_ev(next.stand_ev_in(self.solver, self.control)?))
+                })?
+                .scale(two)),
+            Action::Split => {
+                let Hand::Pair(card) = game.player_hand else {
+                    unreachable!("split is only available for pairs");
+                };
+                let stage = if card == Card::Ace {
+                    Stage::SplitAces
+                } else {
+                    Stage::AfterSplit
+                };
+                Ok(self
+                    .draw(game, |rank, next| {
+                        let next = BlackJackGame {
+                            player_hand: split_hand(card, rank),
+                            player_card_count: 1,
+                            ..next
+                        };
+                        self.ev(&next, stage)
+                    })?
+                    .scale(two))
+            }
+        }
+    }
+
+    // 1枚引いた後の場面の EV を、引く確率で重み付けして足す. peek の条件は player_draw_prob で入る
+    fn draw<F>(&self, game: &BlackJackGame, next_ev: F) -> Result<V, Error>
+    where
+        F: Fn(Card, BlackJackGame) -> Result<V, Error> + Sync,
+    {
+        game.deck_cards
+            .remaining_ranks()
+            .par_iter()
+            .map(|&rank| {
+                self.control.check()?;
+                let next = BlackJackGame {
+                    rule: Arc::clone(&game.rule),
+                    dealer_card: game.dealer_card,
+                    player_hand: game.player_hand.clone(),
+                    deck_cards: game.deck_cards.remove(rank),
+                    player_card_count: game.player_card_count,
+                };
+                Ok(next_ev(rank, next)?.scale(player_draw_prob(game, rank)))
+            })
+            .collect::<Result<Vec<V>, Error>>()
+            .map(|evs| {
+                evs.into_iter()
+                    .fold(V::from_ev(V::Number::from_integer(0)), V::add)
+            })
+    }
+}
+
+// BlackJackGame::action_ev が計算する行動と同じ
+fn available_actions(game: &BlackJackGame, stage: Stage) -> Vec<Action> {
+    let hand = &game.player_hand;
+    let finished = hand.is_21() || hand.is_blackjack() || hand.is_burst();
+
+    let mut actions = vec![Action::Stand];
+    if finished || stage == Stage::SplitAces {
+        return actions;
+    }
+    actions.push(Action::Hit);
+    if stage == Stage::First {
+        actions.push(Action::Double);
+        if hand.is_pair() {
+            actions.push(Action::Split);
+        }
+    }
+    actions
+}
+
+impl BlackJackGame {
+    // 戦略の通りに打ったときのEV. 最初の2枚の場面から
+    pub fn strategy_ev<S: Strategy>(
+        &self,
+        strategy: &S,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Ratio<BigInt>, Error> {
+        self.strategy_ev_as(strategy, cancellation_token)
+    }
+
+    pub fn strategy_ev_as<N: EvNumber, S: Strategy>(
+        &self,
+        strategy: &S,
+        cancellation_token: &CancellationToken,
+    ) -> Result<N, Error> {
+        self.strategy_ev_in(
+            Solver::global(),
+            strategy,
+            &EvControl::from(cancellation_token),
+        )
+    }
+
+    pub(crate) fn strategy_ev_in<N: EvNumber, S: Strategy>(
+        &self,
+        solver: &Solver,
+        strategy: &S,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        Evaluator::new(solver, control, strategy).ev(self, Stage::First)
+    }
+}
+
+impl PreBlackJackGame {
+    // 戦略の通りに打ったときの、このシューで配る前のEV
+    pub fn strategy_ev<S: Strategy>(
+        &self,
+        strategy: &S,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Ratio<BigInt>, Error> {
+        self.strategy_ev_with_control(strategy, &EvControl::from(cancellation_token))
+    }
+
+    pub fn strategy_ev_with_control<N: EvNumber, S: Strategy>(
+        &self,
+        strategy: &S,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        self.partial_strategy_ev_in(Solver::global(), strategy, control)
+            .into_result()
+    }
+
+    pub(crate) fn partial_strategy_ev_in<N: EvNumber, S: Strategy>(
+        &self,
+        solver: &Solver,
+        strategy: &S,
+        control: &EvControl,
+    ) -> PartialEv<N> {
+        // 全パターンで同じ Evaluator を使って、ヒットした後の場面を共有する
+        let evaluator = Evaluator::new(solver, control, strategy);
+        self.partial_ev_with(solver, control, |game| evaluator.ev(game, Stage::First))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Deck, Rule};
+
+    fn small_deck() -> Deck {
+        Deck::new_from_strs(&vec![
+            "A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "10", "10", "10",
+        ])
+    }
+
+    // action_ev で一番良い行動を選ぶ戦略は、最適なEVと一致する
+    fn optimal(decision: &Decision<'_>) -> Action {
+        let action_ev = decision.game.action_ev(&CancellationToken::new()).unwrap();
+        action_ev
+            .ranked()
+            .into_iter()
+            .map(|(action, _)| action)
+            .find(|action| decision.available.contains(action))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_strategy_ev_optimal() {
+        let token = CancellationToken::new();
+        let game = BlackJackGame::new(
+            Rule::evolution_classic(),
+            Card::Face,
+            Hand::Pair(Card::N8),
+            Deck::new(1)
+                .remove(Card::Face)
+                .remove(Card::N8)
+                .remove(Card::N8),
+            2,
+        );
+        assert_eq!(game.strategy_ev(&optimal, &token).unwrap(), game.ev());
+
+        let pre_round = PreBlackJackGame::new(Rule::evolution_classic(), small_deck());
+        assert_eq!(
+            pre_round.strategy_ev(&optimal, &token).unwrap(),
+            pre_round.ev()
+        );
+    }
+
+    #[test]
+    fn test_strategy_ev_always_stand() {
+        let token = CancellationToken::new();
+        let game = BlackJackGame::new(
+            Rule::evolution_classic(),
+            Card::Face,
+            Hand::Hard(HandTotal::Value(16)),
+            Deck::new(8),
+            2,
+        );
+        let always_stand = |_: &Decision<'_>| Action::Stand;
+        assert_eq!(
+            game.strategy_ev(&always_stand, &token).unwrap(),
+            game.stand_ev(&token).unwrap()
+        );
+
+        let pre_round = PreBlackJackGame::new(Rule::evolution_classic(), small_deck());
+        let stand_ev = pre_round.strategy_ev(&always_stand, &token).unwrap();
+        assert!(stand_ev < pre_round.ev());
+    }
+
+    #[test]
+    fn test_strategy_ev_chart() {
+        let token = CancellationToken::new();
+        let rule = Rule::evolution_classic();
+        let chart = StrategyChart::<f64>::generate_as(&rule, &small_deck(), &token).unwrap();
+        let pre_round = PreBlackJackGame::new(rule, small_deck());
+
+        let chart_ev: Ratio<BigInt> = pre_round.strategy_ev(&chart, &token).unwrap();
+        assert!(chart_ev <= pre_round.ev());
+    }
+
+    #[test]
+    fn test_strategy_ev_after_split() {
+        let token = CancellationToken::new();
+        let game = BlackJackGame::new(
+            Rule::evolution_classic(),
+            Card::N6,
+            Hand::Pair(Card::N2),
+            small_deck(),
+            2,
+        );
+
+        // スプリットしたハンドは、ヒットした後も after_split のまま
+        let seen = std::sync::Mutex::new(Vec::new());
+        let split_then_hit = |decision: &Decision<'_>| {
+            if decision.available.contains(&Action::Split) {
+                return Action::Split;
+            }
+            seen.lock()
+                .unwrap()
+                .push((decision.after_split, decision.game.player_card_count));
+            match decision.game.player_hand.hand_total() {
+                HandTotal::Value(total) if total < 15 => Action::Hit,
+                _ => Action::Stand,
+            }
+        };
+        game.strategy_ev_as::<f64, _>(&split_then_hit, &token)
+            .unwrap();
+
+        let seen = seen.into_inner().unwrap();
+        assert!(seen.iter().all(|(after_split, _)| *after_split));
+        assert!(seen.iter().any(|(_, card_count)| *card_count > 1));
+    }
+
+    #[test]
+    fn test_strategy_ev_invalid_action() {
+        let token = CancellationToken::new();
+        let game = BlackJackGame::new(
+            Rule::evolution_classic(),
+            Card::Face,
+            Hand::Hard(HandTotal::Value(16)),
+            Deck::new(1),
+            2,
+        );
+        let always_split = |_: &Decision<'_>| Action::Split;
+        assert!(matches!(
+            game.strategy_ev(&always_split, &token),
+            Err(Error::InvalidAction(_))
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+pub mod chart;
+pub mod composition;
+pub mod evaluate;
+pub mod regret;
+pub mod render;
+
+pub use chart::{RowKind, StrategyCell, StrategyChart, StrategyRow, DEALER_CARDS};
+pub use composition::CompositionException;
+pub use evaluate::{Decision, Strategy};
+pub use regret::{Mistake, RegretReport};
+pub use render::ChartCode;
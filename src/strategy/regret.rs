@@ -0,0 +1,316 @@
+use crate::cache::CacheWeight;
+use crate::game::{Action, ActionEV, BlackJackGame, EvControl, EvNumber, PreBlackJackGame, Solver};
+use crate::models::{Card, Hand};
+use crate::strategy::evaluate::{Evaluator, Outcome, Stage};
+use crate::strategy::{Decision, Strategy};
+use crate::Error;
+
+use num::bigint::BigInt;
+use num::rational::Ratio;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use tokio_util::sync::CancellationToken;
+
+// 戦略の間違い. 同じハンド・アップカードでの同じ間違いはまとめる
+#[derive(Debug, Clone)]
+pub struct Mistake<N = Ratio<BigInt>> {
+    pub hand: Hand,
+    pub dealer_card: Card,
+    pub chosen: Action,
+    pub best: Action,
+    // 1ラウンドでこの場面に出会う回数の期待値. スプリットした2ハンドはそれぞれ数える
+    pub frequency: N,
+    // 1ラウンドあたりに失うEV. その先は最適に打ったときの最適な行動との差を、出会う回数で重み付けしたもの
+    pub cost: N,
+}
+
+#[derive(Debug, Clone)]
+pub struct RegretReport<N = Ratio<BigInt>> {
+    // cost の大きい順
+    pub mistakes: Vec<Mistake<N>>,
+    // cost の合計. 最適なEVと戦略のEVの差になる
+    pub total_cost: N,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct MistakeKey {
+    hand: Hand,
+    dealer_card: Card,
+    chosen: Action,
+    best: Action,
+}
+
+// Evaluator が場面ごとに返す値. その場面に来たときの、そこから先のEVと間違いの内訳
+#[derive(Debug, Clone)]
+struct Regrets<N> {
+    ev: N,
+    // (frequency, cost)
+    mistakes: HashMap<MistakeKey, (N, N)>,
+}
+
+impl<N: EvNumber> CacheWeight for Regrets<N> {
+    fn cache_weight(&self) -> usize {
+        size_of::<Self>()
+            + self.ev.cache_weight()
+            + self
+                .mistakes
+                .values()
+                .map(|(frequency, cost)| {
+                    size_of::<MistakeKey>() + frequency.cache_weight() + cost.cache_weight()
+                })
+                .sum::<usize>()
+    }
+}
+
+impl<N: EvNumber> Outcome for Regrets<N> {
+    type Number = N;
+
+    fn from_ev(ev: N) -> Self {
+        Regrets {
+            ev,
+            mistakes: HashMap::new(),
+        }
+    }
+
+    fn add(mut self, other: Self) -> Self {
+        self.ev = self.ev + other.ev;
+        for (key, (frequency, cost)) in other.mistakes {
+            let (f, c) = self
+                .mistakes
+                .remove(&key)
+                .unwrap_or((N::from_integer(0), N::from_integer(0)));
+            self.mistakes.insert(key, (f + frequency, c + cost));
+        }
+        self
+    }
+
+    fn scale(self, factor: N) -> Self {
+        Regrets {
+            ev: self.ev * factor.clone(),
+            mistakes: self
+                .mistakes
+                .into_iter()
+                .map(|(key, (frequency, cost))| {
+                    (key, (frequency * factor.clone(), cost * factor.clone()))
+                })
+                .collect(),
+        }
+    }
+
+    // 最適な行動と比べて、選んだ行動のEVが低ければ間違いとして記録する
+    fn decided(
+        self,
+        decision: &Decision<'_>,
+        action: Action,
+        solver: &Solver,
+        control: &EvControl,
+    ) -> Result<Self, Error> {
+        let action_ev = available_only(
+            decision.game.action_ev_in::<N>(solver, control)?,
+            decision.available,
+        );
+        let best = action_ev.best_action();
+        let regret = match action_ev.regret(action) {
+            Some(regret) if best != action && regret > N::from_integer(0) => regret,
+            _ => return Ok(self),
+        };
+
+        let key = MistakeKey {
+            hand: decision.game.player_hand.clone(),
+            dealer_card: decision.game.dealer_card,
+            chosen: action,
+            best,
+        };
+        let mut mistake = Regrets::from_ev(N::from_integer(0));
+        mistake.mistakes.insert(key, (N::from_integer(1), regret));
+        Ok(self.add(mistake))
+    }
+}
+
+impl<N: EvNumber> Regrets<N> {
+    fn into_report(self) -> RegretReport<N> {
+        let mut mistakes: Vec<Mistake<N>> = self
+            .mistakes
+            .into_iter()
+            .map(|(key, (frequency, cost))| Mistake {
+                hand: key.hand,
+                dealer_card: key.dealer_card,
+                chosen: key.chosen,
+                best: key.best,
+                frequency,
+                cost,
+            })
+            .collect();
+        mistakes.sort_by(|a, b| {
+            b.cost
+                .partial_cmp(&a.cost)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let total_cost = mistakes.iter().map(|mistake| mistake.cost.clone()).sum();
+        RegretReport {
+            mistakes,
+            total_cost,
+        }
+    }
+}
+
+// action_ev には3枚目以降のダブルなども入っているので、その場面で選べる行動だけにする
+fn available_only<N>(action_ev: ActionEV<N>, available: &[Action]) -> ActionEV<N> {
+    let available = |action: Action| available.contains(&action);
+    ActionEV {
+        stand: action_ev.stand,
+        hit: action_ev.hit.filter(|_| available(Action::Hit)),
+        double: action_ev.double.filter(|_| available(Action::Double)),
+        split: action_ev.split.filter(|_| available(Action::Split)),
+    }
+}
+
+impl BlackJackGame {
+    // この場面で action を選んだときに、最適な行動と比べて失うEV
+    // 選べる行動は action_ev と同じ. 選べない行動なら InvalidAction
+    pub fn regret(
+        &self,
+        action: Action,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Ratio<BigInt>, Error> {
+        self.regret_as(action, cancellation_token)
+    }
+
+    pub fn regret_as<N: EvNumber>(
+        &self,
+        action: Action,
+        cancellation_token: &CancellationToken,
+    ) -> Result<N, Error> {
+        self.regret_in(
+            Solver::global(),
+            action,
+            &EvControl::from(cancellation_token),
+        )
+    }
+
+    pub(crate) fn regret_in<N: EvNumber>(
+        &self,
+        solver: &Solver,
+        action: Action,
+        control: &EvControl,
+    ) -> Result<N, Error> {
+        self.action_ev_in::<N>(solver, control)?
+            .regret(action)
+            .ok_or_else(|| {
+                Error::InvalidAction(format!(
+                    "{:?} is not available for {:?} against {:?}",
+                    action, self.player_hand, self.dealer_card
+                ))
+            })
+    }
+}
+
+impl PreBlackJackGame {
+    // strategy の通りに打ったときの間違いを、その場面に出会う確率で重み付けして集計する
+    pub fn regret_report<S: Strategy>(
+        &self,
+        strategy: &S,
+        cancellation_token: &CancellationToken,
+    ) -> Result<RegretReport, Error> {
+        self.regret_report_with_control(strategy, &EvControl::from(cancellation_token))
+    }
+
+    pub fn regret_report_with_control<N: EvNumber, S: Strategy>(
+        &self,
+        strategy: &S,
+        control: &EvControl,
+    ) -> Result<RegretReport<N>, Error> {
+        self.regret_report_in(Solver::global(), strategy, control)
+    }
+
+    pub(crate) fn regret_report_in<N: EvNumber, S: Strategy>(
+        &self,
+        solver: &Solver,
+        strategy: &S,
+        control: &EvControl,
+    ) -> Result<RegretReport<N>, Error> {
+        let evaluator = Evaluator::<S, Regrets<N>>::new(solver, control, strategy);
+        let regrets = self
+            .dealt_games::<N>(solver)?
+            .par_iter()
+            .map(|(game, prob)| {
+                control.check()?;
+                Ok(evaluator.ev(game, Stage::First)?.scale(prob.clone()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(regrets
+            .into_iter()
+            .fold(Regrets::from_ev(N::from_integer(0)), Regrets::add)
+            .into_report())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Deck, HandTotal, Rule};
+
+    fn small_deck() -> Deck {
+        Deck::new_from_strs(&vec![
+            "A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "10", "10", "10",
+        ])
+    }
+
+    #[test]
+    fn test_regret() {
+        let token = CancellationToken::new();
+        let game = BlackJackGame::new(
+            Rule::evolution_classic(),
+            Card::Face,
+            Hand::Hard(HandTotal::Value(16)),
+            Deck::new(1).remove(Card::Face).remove(Card::Face),
+            2,
+        );
+        let action_ev: ActionEV<f64> = game.action_ev_as(&token).unwrap();
+
+        let best = action_ev.best_action();
+        assert_eq!(game.regret_as::<f64>(best, &token).unwrap(), 0.0);
+        let stand = game.regret_as::<f64>(Action::Stand, &token).unwrap();
+        assert_eq!(Some(stand), action_ev.regret(Action::Stand));
+        assert!(stand > 0.0);
+
+        assert!(matches!(
+            game.regret_as::<f64>(Action::Split, &token),
+            Err(Error::InvalidAction(_))
+        ));
+    }
+
+    #[test]
+    fn test_regret_report() {
+        let token = CancellationToken::new();
+        let pre_round = PreBlackJackGame::new(Rule::evolution_classic(), small_deck());
+
+        let always_stand = |_: &Decision<'_>| Action::Stand;
+        let report = pre_round.regret_report(&always_stand, &token).unwrap();
+        assert!(!report.mistakes.is_empty());
+        assert!(report.mistakes.windows(2).all(|w| w[0].cost >= w[1].cost));
+        for mistake in &report.mistakes {
+            assert_eq!(mistake.chosen, Action::Stand);
+            assert_ne!(mistake.best, Action::Stand);
+            assert!(mistake.cost > Ratio::from_integer(BigInt::from(0)));
+            assert!(mistake.frequency > Ratio::from_integer(BigInt::from(0)));
+        }
+        // 間違いの cost を全部足すと、最適なEVとの差になる
+        let stand_ev = pre_round.strategy_ev(&always_stand, &token).unwrap();
+        assert_eq!(report.total_cost, pre_round.ev() - stand_ev);
+    }
+
+    #[test]
+    fn test_regret_report_optimal() {
+        let token = CancellationToken::new();
+        let pre_round = PreBlackJackGame::new(Rule::evolution_classic(), small_deck());
+
+        let optimal = |decision: &Decision<'_>| {
+            let action_ev: ActionEV = decision.game.action_ev(&CancellationToken::new()).unwrap();
+            available_only(action_ev, decision.available).best_action()
+        };
+        let report = pre_round.regret_report(&optimal, &token).unwrap();
+        assert!(report.mistakes.is_empty());
+        assert_eq!(report.total_cost, Ratio::from_integer(BigInt::from(0)));
+    }
+}
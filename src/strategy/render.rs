@@ -0,0 +1,378 @@
+use crate::game::{Action, EvNumber};
+use crate::models::Card;
+use crate::strategy::{RowKind, StrategyCell, StrategyChart, StrategyRow, DEALER_CARDS};
+
+use std::fmt::Write;
+
+// チャートの記号
+// Rh はマスにサレンダーのEVがあるときだけ出る (StrategyCell::with_surrender)
+// スプリット後のダブルは計算していないので Ph などはない
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChartCode {
+    Hit,
+    Stand,
+    // ダブル. できなければヒット
+    DoubleOrHit,
+    // ダブル. できなければスタンド
+    DoubleOrStand,
+    Split,
+    // サレンダー. できなければヒット
+    SurrenderOrHit,
+}
+
+impl ChartCode {
+    pub const ALL: [ChartCode; 6] = [
+        ChartCode::Hit,
+        ChartCode::Stand,
+        ChartCode::DoubleOrHit,
+        ChartCode::DoubleOrStand,
+        ChartCode::Split,
+        ChartCode::SurrenderOrHit,
+    ];
+
+    // サレンダーが一番良く、できなければヒットするマスは Rh
+    pub fn from_cell<N: EvNumber>(cell: &StrategyCell<N>) -> ChartCode {
+        let surrender_is_best = match (&cell.surrender, cell.action_ev.get(cell.best)) {
+            (Some(surrender), Some(best_ev)) => surrender > best_ev,
+            _ => false,
+        };
+        match cell.best {
+            Action::Hit if surrender_is_best => ChartCode::SurrenderOrHit,
+            Action::Hit => ChartCode::Hit,
+            Action::Stand => ChartCode::Stand,
+            Action::Split => ChartCode::Split,
+            Action::Double => match cell.fallback() {
+                Action::Stand => ChartCode::DoubleOrStand,
+                _ => ChartCode::DoubleOrHit,
+            },
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChartCode::Hit => "H",
+            ChartCode::Stand => "S",
+            ChartCode::DoubleOrHit => "D",
+            ChartCode::DoubleOrStand => "Ds",
+            ChartCode::Split => "P",
+            ChartCode::SurrenderOrHit => "Rh",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            ChartCode::Hit => "Hit",
+            ChartCode::Stand => "Stand",
+            ChartCode::DoubleOrHit => "Double if allowed, otherwise hit",
+            ChartCode::DoubleOrStand => "Double if allowed, otherwise stand",
+            ChartCode::Split => "Split",
+            ChartCode::SurrenderOrHit => "Surrender if allowed, otherwise hit",
+        }
+    }
+
+    fn color(&self) -> &'static str {
+        match self {
+            ChartCode::Hit => "#f4cccc",
+            ChartCode::Stand => "#fff2cc",
+            ChartCode::DoubleOrHit => "#b6d7a8",
+            ChartCode::DoubleOrStand => "#6aa84f",
+            ChartCode::Split => "#9fc5e8",
+            ChartCode::SurrenderOrHit => "#d9d9d9",
+        }
+    }
+}
+
+impl<N: EvNumber> StrategyCell<N> {
+    pub fn code(&self) -> ChartCode {
+        ChartCode::from_cell(self)
+    }
+
+    // HTML のツールチップ用. 行動ごとのEVと、2番目との差
+    fn ev_summary(&self) -> String {
+        let mut lines: Vec<String> = self
+            .action_ev
+            .ranked()
+            .iter()
+            .map(|(action, ev)| format!("{:?}: {:+.4}", action, ev.as_f64()))
+            .collect();
+        if let Some(surrender) = &self.surrender {
+            lines.push(format!("Surrender: {:+.4}", surrender.as_f64()));
+        }
+        if let Some(margin) = &self.margin {
+            lines.push(format!("Margin: {:.4}", margin.as_f64()));
+        }
+        lines.join("\n")
+    }
+}
+
+impl<N> StrategyRow<N> {
+    pub fn label(&self) -> String {
+        match self.kind {
+            RowKind::Hard => format!("Hard {}", hand_value(self)),
+            RowKind::Soft => format!("Soft {}", hand_value(self)),
+            RowKind::Pair => {
+                let card = card_label(self.cards.cards[0]);
+                format!("{},{}", card, card)
+            }
+        }
+    }
+}
+
+// 代表の手札は A を1枚までしか含まないので、そのまま足せばよい
+fn hand_value<N>(row: &StrategyRow<N>) -> usize {
+    row.cards.cards.iter().map(|card| card.value()).sum()
+}
+
+fn card_label(card: Card) -> &'static str {
+    match card {
+        Card::Ace => "A",
+        Card::N2 => "2",
+        Card::N3 => "3",
+        Card::N4 => "4",
+        Card::N5 => "5",
+        Card::N6 => "6",
+        Card::N7 => "7",
+        Card::N8 => "8",
+        Card::N9 => "9",
+        Card::Face => "T",
+    }
+}
+
+fn cell_code<N: EvNumber>(cell: &Option<StrategyCell<N>>) -> &'static str {
+    cell.as_ref().map_or("", |cell| cell.code().as_str())
+}
+
+impl<N: EvNumber> StrategyChart<N> {
+    fn sections(&self) -> [(&'static str, &Vec<StrategyRow<N>>); 3] {
+        [
+            ("Hard totals", &self.hard),
+            ("Soft totals", &self.soft),
+            ("Pairs", &self.pairs),
+        ]
+    }
+
+    // 1行目が見出し. 配れないマスは空欄
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("Hand");
+        for card in DEALER_CARDS {
+            write!(out, ",{}", card_label(card)).unwrap();
+        }
+        out.push('\n');
+        for row in self.rows() {
+            out.push_str(&csv_field(&row.label()));
+            for cell in &row.cells {
+                write!(out, ",{}", cell_code(cell)).unwrap();
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        for (title, rows) in self.sections() {
+            writeln!(out, "### {}\n", title).unwrap();
+            out.push_str("| Hand |");
+            for card in DEALER_CARDS {
+                write!(out, " {} |", card_label(card)).unwrap();
+            }
+            out.push_str("\n|---|");
+            out.push_str(&":-:|".repeat(DEALER_CARDS.len()));
+            out.push('\n');
+            for row in rows {
+                write!(out, "| {} |", row.label()).unwrap();
+                for cell in &row.cells {
+                    write!(out, " {} |", cell_code(cell)).unwrap();
+                }
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+        out.push_str(&legend(|code| {
+            format!("- **{}**: {}\n", code.as_str(), code.description())
+        }));
+        out
+    }
+
+    // 単体で開ける HTML. マスにカーソルを乗せると行動ごとのEVが出る
+    pub fn to_html(&self) -> String {
+        let mut out = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Blackjack strategy chart</title>\n<style>\n\
+             body { font-family: sans-serif; }\n\
+             table { border-collapse: collapse; margin-bottom: 1.5em; }\n\
+             th, td { border: 1px solid #999; padding: 4px 8px; text-align: center; }\n\
+             th { background: #eee; }\n",
+        );
+        for code in ChartCode::ALL {
+            writeln!(
+                out,
+                "td.{} {{ background: {}; }}",
+                css_class(code),
+                code.color()
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "</style>\n</head>\n<body>\n<h1>Strategy chart</h1>\n<p>{} decks, {} cards in the shoe</p>",
+            self.rule.decks,
+            self.deck.total_cards()
+        )
+        .unwrap();
+
+        for (title, rows) in self.sections() {
+            writeln!(out, "<h2>{}</h2>\n<table>\n<tr><th>Hand</th>", title).unwrap();
+            for card in DEALER_CARDS {
+                write!(out, "<th>{}</th>", card_label(card)).unwrap();
+            }
+            out.push_str("</tr>\n");
+            for row in rows {
+                write!(out, "<tr><th>{}</th>", row.label()).unwrap();
+                for cell in &row.cells {
+                    match cell {
+                        Some(cell) => write!(
+                            out,
+                            "<td class=\"{}\" title=\"{}\">{}</td>",
+                            css_class(cell.code()),
+                            cell.ev_summary().replace('\n', "&#10;"),
+                            cell.code().as_str()
+                        )
+                        .unwrap(),
+                        None => out.push_str("<td></td>"),
+                    }
+                }
+                out.push_str("</tr>\n");
+            }
+            out.push_str("</table>\n");
+        }
+
+        out.push_str("<ul>\n");
+        out.push_str(&legend(|code| {
+            format!(
+                "<li><span style=\"background: {}\">{}</span> {}</li>\n",
+                code.color(),
+                code.as_str(),
+                code.description()
+            )
+        }));
+        out.push_str("</ul>\n</body>\n</html>\n");
+        out
+    }
+}
+
+// ペアの見出し (8,8) はカンマを含むので囲む
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn legend(line: impl Fn(ChartCode) -> String) -> String {
+    ChartCode::ALL.into_iter().map(line).collect()
+}
+
+fn css_class(code: ChartCode) -> String {
+    format!("code-{}", code.as_str().to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::ActionEV;
+    use crate::models::{Cards, Deck, Hand, HandTotal, Rule};
+    use smallvec::smallvec;
+
+    fn cell(stand: f64, hit: f64, double: Option<f64>, split: Option<f64>) -> StrategyCell<f64> {
+        StrategyCell::new(ActionEV {
+            stand,
+            hit: Some(hit),
+            double,
+            split,
+        })
+    }
+
+    // hard 11 (9,2) と 8,8 の2行だけのチャート
+    fn chart() -> StrategyChart<f64> {
+        let mut hard_cells = vec![Some(cell(-0.2, 0.1, Some(0.3), None)); DEALER_CARDS.len()];
+        hard_cells[9] = None;
+        StrategyChart {
+            rule: Rule::evolution_classic(),
+            deck: Deck::new(8),
+            hard: vec![StrategyRow {
+                kind: RowKind::Hard,
+                hand: Hand::Hard(HandTotal::Value(11)),
+                cards: Cards::from_smallvec(smallvec![Card::N9, Card::N2]),
+                cells: hard_cells,
+            }],
+            soft: vec![],
+            pairs: vec![StrategyRow {
+                kind: RowKind::Pair,
+                hand: Hand::Pair(Card::N8),
+                cards: Cards::from_smallvec(smallvec![Card::N8, Card::N8]),
+                cells: vec![Some(cell(-0.5, -0.4, Some(-0.9), Some(-0.1))); DEALER_CARDS.len()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_code() {
+        assert_eq!(
+            cell(-0.2, 0.1, Some(0.3), None).code(),
+            ChartCode::DoubleOrHit
+        );
+        assert_eq!(
+            cell(0.2, 0.1, Some(0.3), None).code(),
+            ChartCode::DoubleOrStand
+        );
+        assert_eq!(cell(0.2, 0.1, None, None).code(), ChartCode::Stand);
+        assert_eq!(cell(-0.5, -0.4, None, Some(-0.1)).code(), ChartCode::Split);
+
+        // 16 対 T. ヒットよりサレンダーが良い
+        let hard_16 = cell(-0.54, -0.51, Some(-1.02), None);
+        assert_eq!(hard_16.code(), ChartCode::Hit);
+        assert_eq!(
+            hard_16.clone().with_surrender(-0.5).code(),
+            ChartCode::SurrenderOrHit
+        );
+        assert_eq!(ChartCode::SurrenderOrHit.as_str(), "Rh");
+        // サレンダーより良い行動があれば、いつもの記号
+        assert_eq!(
+            cell(-0.2, 0.1, Some(0.3), None).with_surrender(-0.5).code(),
+            ChartCode::DoubleOrHit
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            chart().to_csv(),
+            "Hand,2,3,4,5,6,7,8,9,T,A\n\
+             Hard 11,D,D,D,D,D,D,D,D,D,\n\
+             \"8,8\",P,P,P,P,P,P,P,P,P,P\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = chart().to_markdown();
+        assert!(markdown.contains("| Hand | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | T | A |\n"));
+        assert!(markdown.contains("| Hard 11 | D | D | D | D | D | D | D | D | D |  |\n"));
+        assert!(markdown.contains("| 8,8 | P |"));
+        assert!(markdown.contains("- **Ds**: Double if allowed, otherwise stand"));
+        assert!(markdown.contains("- **Rh**: Surrender if allowed, otherwise hit"));
+    }
+
+    #[test]
+    fn test_to_html() {
+        let html = chart().to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(
+            "<td class=\"code-d\" title=\"Double: +0.3000&#10;Hit: +0.1000&#10;Stand: -0.2000&#10;Margin: 0.2000\">D</td>"
+        ));
+        assert!(html.contains("td.code-p { background: #9fc5e8; }"));
+        assert!(html.ends_with("</html>\n"));
+    }
+}